@@ -3,7 +3,7 @@ use iced::{
     Button, Column, Container, Element, Length, Sandbox, Scrollable, Settings, Text
 };
 
-use iced_aw::{style, Card};
+use iced_aw::{card, style, Card};
 
 fn main() -> iced::Result {
     CardExample::run(Settings::default())
@@ -13,10 +13,13 @@ fn main() -> iced::Result {
 enum Message {
     CloseCard,
     OpenCard,
+    ToggleCard,
 }
 
 struct CardExample {
     card_open: bool,
+    card_expanded: bool,
+    card_scroll_state: card::ScrollState,
     button_state: button::State,
     scrollable_state: scrollable::State,
 }
@@ -28,6 +31,8 @@ impl Sandbox for CardExample {
     fn new() -> Self {
         CardExample {
             card_open: true,
+            card_expanded: true,
+            card_scroll_state: card::ScrollState::new(),
             button_state: button::State::new(),
             scrollable_state: scrollable::State::new(),
         }
@@ -42,6 +47,9 @@ impl Sandbox for CardExample {
             Message::CloseCard | Message::OpenCard => {
                 self.card_open = !self.card_open;
             }
+            Message::ToggleCard => {
+                self.card_expanded = !self.card_expanded;
+            }
         }
     }
 
@@ -54,7 +62,11 @@ impl Sandbox for CardExample {
                     .push(Text::new("Zombie ipsum reversus ab viral inferno, nam rick grimes malum cerebro. De carne lumbering animata corpora quaeritis. Summus brains sit​​, morbo vel maleficia? De apocalypsi gorger omero undead survivor dictum mauris. Hi mindless mortuis soulless creaturas, imo evil stalking monstra adventus resi dentevil vultus comedat cerebella viventium. Qui animated corpse, cricket bat max brucks terribilem incessu zomby. The voodoo sacerdos flesh eater, suscitat mortuos comedere carnem virus. Zonbi tattered for solum oculi eorum defunctis go lum cerebro. Nescio brains an Undead zombies. Sicut malus putrid voodoo horror. Nigh tofth eliv ingdead."))
             )
             .foot(Text::new("Foot"))
+            .max_height(250)
+            .scrollable(&mut self.card_scroll_state)
+            .expanded(self.card_expanded)
             .style(style::card::Primary)
+            .on_toggle(Message::ToggleCard)
             .on_close(Message::CloseCard)
             .into()
         } else {
@@ -1,8 +1,12 @@
+use std::time::Duration;
+
 use iced::{
-    button, Align, Button, Container, Element, HorizontalAlignment, Length, Row, Sandbox, Settings, Text,
+    button, executor, time, Align, Application, Button, Column, Command, Container,
+    Element, HorizontalAlignment, Length, Row, Settings, Subscription, Text,
 };
 
 use iced_aw::{modal, Card, Modal};
+use modal::{Animation, Easing, Placement};
 
 fn main() -> iced::Result {
     ModalExample::run(Settings::default())
@@ -10,14 +14,16 @@ fn main() -> iced::Result {
 
 #[derive(Clone, Debug)]
 enum Message {
-    OpenModal,
+    OpenModal(Placement),
     CloseModal,
+    Tick,
     CancelButtonPressed,
     OkButtonPressed,
 }
 
 struct ModalExample {
-    open_state: button::State,
+    open_states: [button::State; 3],
+    placement: Placement,
     modal_state: modal::State<ModalState>,
     last_message: Option<Message>,
 }
@@ -28,46 +34,81 @@ struct ModalState {
     ok_state: button::State,
 }
 
-impl Sandbox for ModalExample {
-    
+impl Application for ModalExample {
+    type Executor = executor::Default;
     type Message = Message;
+    type Flags = ();
 
-    fn new() -> Self {
-        ModalExample {
-            open_state: button::State::new(),
-            modal_state: modal::State::new(ModalState::default()),
-            last_message: None,
-        }
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            ModalExample {
+                open_states: Default::default(),
+                placement: Placement::Center,
+                modal_state: modal::State::new(ModalState::default()),
+                last_message: None,
+            },
+            Command::none(),
+        )
     }
 
     fn title(&self) -> String {
         String::from("Modal example")
     }
 
-    fn update(&mut self, message: Self::Message) {
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
         match message {
-            Message::OpenModal => self.modal_state.show(true),
+            Message::OpenModal(placement) => {
+                self.placement = placement;
+                self.modal_state.show(true)
+            },
             Message::CloseModal => self.modal_state.show(false),
+            Message::Tick => return Command::none(),
             Message::CancelButtonPressed => self.modal_state.show(false),
             Message::OkButtonPressed => self.modal_state.show(false),
         }
-        self.last_message = Some(message)
+        self.last_message = Some(message);
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        // Iced only redraws after events, so the animation needs some ticks.
+        if self.modal_state.is_animating() {
+            time::every(Duration::from_millis(16)).map(|_| Message::Tick)
+        } else {
+            Subscription::none()
+        }
     }
 
     fn view(&mut self) -> Element<'_, Self::Message> {
+        let [center_state, bottom_state, left_state] = &mut self.open_states;
+
         let content = Container::new(
-            Row::new()
+            Column::new()
                 .spacing(10)
                 .align_items(Align::Center)
                 .push(
-                    Button::new(&mut self.open_state, Text::new("Open modal!"))
-                        .on_press(Message::OpenModal)
+                    Row::new()
+                        .spacing(10)
+                        .push(
+                            Button::new(center_state, Text::new("Open modal!"))
+                                .on_press(Message::OpenModal(Placement::Center))
+                        )
+                        .push(
+                            Button::new(bottom_state, Text::new("Open bottom sheet!"))
+                                .on_press(Message::OpenModal(Placement::Bottom))
+                        )
+                        .push(
+                            Button::new(left_state, Text::new("Open drawer!"))
+                                .on_press(Message::OpenModal(Placement::Left))
+                        )
                 ).push(
                     Text::new(format!("Last message: {}",
                         match self.last_message.as_ref() {
                             Some(message) => match message {
-                                Message::OpenModal => "Modal opened",
+                                Message::OpenModal(_) => "Modal opened",
                                 Message::CloseModal => "Modal closed",
+                                Message::Tick => "Tick",
                                 Message::CancelButtonPressed => "Modal canceled",
                                 Message::OkButtonPressed => "Modal accepted",
                             }
@@ -118,6 +159,8 @@ impl Sandbox for ModalExample {
         )
         .backdrop(Message::CloseModal)
         .on_esc(Message::CloseModal)
+        .placement(self.placement)
+        .animation(Animation::new(Duration::from_millis(250)).easing(Easing::EaseInOut))
         .into()
     }
 }
\ No newline at end of file
@@ -2,7 +2,7 @@ use iced::{
     Align, Button, Column, Element, Length, Sandbox, Settings, Row, Text, TextInput,
     button, text_input
 };
-use iced_aw::{TabBar, TabLabel};
+use iced_aw::{TabBar, TabLabel, tab_bar};
 
 fn main() -> iced::Result {
     TabBarExample::run(Settings::default())
@@ -12,19 +12,23 @@ fn main() -> iced::Result {
 enum Message {
     TabSelected(usize),
     TabClosed(usize),
+    TabReordered(usize, usize),
     TabLabelInputChanged(String),
     TabContentInputChanged(String),
     NewTab,
+    TogglePinned,
 }
 
 struct TabBarExample {
     active_tab: usize,
+    tab_bar_state: tab_bar::State,
     tab_label_input: text_input::State,
     new_tab_label: String,
     tab_content_input: text_input::State,
     new_tab_content: String,
     new_button: button::State,
-    tabs: Vec<(String, String)>,
+    pin_button: button::State,
+    tabs: Vec<(String, String, bool)>,
 }
 
 impl Sandbox for TabBarExample {
@@ -34,11 +38,13 @@ impl Sandbox for TabBarExample {
     fn new() -> Self {
         TabBarExample {
             active_tab: 0,
+            tab_bar_state: tab_bar::State::new(),
             tab_label_input: text_input::State::new(),
             new_tab_label: String::new(),
             tab_content_input: text_input::State::new(),
             new_tab_content: String::new(),
             new_button: button::State::new(),
+            pin_button: button::State::new(),
             tabs: Vec::new(),
         }
     }
@@ -62,6 +68,11 @@ impl Sandbox for TabBarExample {
                 };
                 println!("active tab after: {}", self.active_tab);
             }
+            Message::TabReordered(from, to) => {
+                let tab = self.tabs.remove(from);
+                self.tabs.insert(to, tab);
+                self.active_tab = to;
+            },
             Message::TabLabelInputChanged(value) => {
                 self.new_tab_label = value
             },
@@ -72,11 +83,16 @@ impl Sandbox for TabBarExample {
                 println!("New");
                 if !self.new_tab_label.is_empty() && !self.new_tab_content.is_empty() {
                     println!("Create");
-                    self.tabs.push((self.new_tab_label.to_owned(), self.new_tab_content.to_owned()));
+                    self.tabs.push((self.new_tab_label.to_owned(), self.new_tab_content.to_owned(), false));
                     self.new_tab_label.clear();
                     self.new_tab_content.clear();
                 }
             }
+            Message::TogglePinned => {
+                if let Some((_, _, pinned)) = self.tabs.get_mut(self.active_tab) {
+                    *pinned = !*pinned;
+                }
+            }
         }
     }
     
@@ -111,6 +127,13 @@ impl Sandbox for TabBarExample {
                         )
                         .on_press(Message::NewTab)
                     )
+                    .push(
+                        Button::new(
+                            &mut self.pin_button,
+                            Text::new("Pin"),
+                        )
+                        .on_press(Message::TogglePinned)
+                    )
                     .align_items(Align::Center)
                     .padding(10)
                     .spacing(5)
@@ -122,14 +145,25 @@ impl Sandbox for TabBarExample {
                     .push(TabLabel::Text(String::from("Three")))*/
 
                 self.tabs.iter()
+                    .enumerate()
                     .fold(
-                        TabBar::new(self.active_tab, Message::TabSelected),
-                        |tab_bar, (tab_label, _)| {
-                            tab_bar.push(TabLabel::Text(tab_label.to_owned()))
+                        TabBar::new(
+                            &mut self.tab_bar_state,
+                            self.active_tab,
+                            Message::TabSelected,
+                        ),
+                        |tab_bar, (index, (tab_label, _, pinned))| {
+                            tab_bar
+                                .push(TabLabel::Text(tab_label.to_owned()))
+                                .tab_pinned(index, *pinned)
                         }
                     )
                     .on_close(Message::TabClosed)
+                    .on_reorder(Message::TabReordered)
+                    .on_new(Message::NewTab)
+                    .overflow_menu(true)
                     .tab_width(Length::Shrink)
+                    .max_tab_width(200)
                     .spacing(5)
                     .padding(5)
                     .text_size(32)
@@ -141,7 +175,7 @@ impl Sandbox for TabBarExample {
                     2 => Text::new("This is tab three"),
                     _ => panic!(),
                 }*/
-                if let Some((_, content)) = self.tabs.get(self.active_tab) {
+                if let Some((_, content, _)) = self.tabs.get(self.active_tab) {
                     Text::new(content)
                 } else {
                     Text::new("Please create a new tab")
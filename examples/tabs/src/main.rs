@@ -1,7 +1,7 @@
 use iced::{Align, Column, Container, Element, Font, Length, Sandbox,
     Settings, Text, };
 
-use iced_aw::{Tabs, TabLabel};
+use iced_aw::{Tabs, TabLabel, tab_bar};
 
 mod login;
 use login::{LoginMessage, LoginTab};
@@ -49,6 +49,7 @@ fn main() -> iced::Result {
 
 struct TabBarExample {
     active_tab: usize,
+    tab_bar_state: tab_bar::State,
     login_tab: LoginTab,
     ferris_tab: FerrisTab,
     counter_tab: CounterTab,
@@ -70,6 +71,7 @@ impl Sandbox for TabBarExample {
     fn new() -> Self {
         TabBarExample {
             active_tab: 0,
+            tab_bar_state: tab_bar::State::new(),
             login_tab: LoginTab::new(),
             ferris_tab: FerrisTab::new(),
             counter_tab: CounterTab::new(),
@@ -107,7 +109,7 @@ impl Sandbox for TabBarExample {
         let theme = self.settings_tab.settings().tab_bar_theme
             .unwrap_or_default();
 
-        Tabs::new(self.active_tab, Message::TabSelected)
+        let tabs = Tabs::new(&mut self.tab_bar_state, self.active_tab, Message::TabSelected)
             .push(self.login_tab.tab_label(), self.login_tab.view())
             .push(self.ferris_tab.tab_label(), self.ferris_tab.view())
             .push(self.counter_tab.tab_label(), self.counter_tab.view())
@@ -117,8 +119,17 @@ impl Sandbox for TabBarExample {
             .tab_bar_position(match position {
                 TabBarPosition::Top => iced_aw::TabBarPosition::Top,
                 TabBarPosition::Bottom => iced_aw::TabBarPosition::Bottom,
-            })
-            .into()
+                TabBarPosition::Left => iced_aw::TabBarPosition::Left,
+                TabBarPosition::Right => iced_aw::TabBarPosition::Right,
+            });
+
+        match position {
+            TabBarPosition::Left | TabBarPosition::Right => tabs
+                .tab_bar_width(Length::Shrink)
+                .tab_width(Length::Shrink),
+            _ => tabs,
+        }
+        .into()
     }
 }
 
@@ -6,12 +6,16 @@ use iced_aw::{TabLabel};
 pub enum TabBarPosition {
     Top,
     Bottom,
+    Left,
+    Right,
 }
 
 impl TabBarPosition {
-    pub const ALL: [TabBarPosition; 2] = [
+    pub const ALL: [TabBarPosition; 4] = [
         TabBarPosition::Top,
         TabBarPosition::Bottom,
+        TabBarPosition::Left,
+        TabBarPosition::Right,
     ];
 }
 
@@ -20,6 +24,8 @@ impl From<TabBarPosition> for String {
         String::from(match position {
             TabBarPosition::Top => "Top",
             TabBarPosition::Bottom => "Bottom",
+            TabBarPosition::Left => "Left",
+            TabBarPosition::Right => "Right",
         })
     }
 }
@@ -51,7 +51,7 @@ impl From<Theme> for Box<dyn tabs::StyleSheet> {
 
 mod red {
     use iced::{Background, Color};
-    use iced_aw::tabs::{self, Style};
+    use iced_aw::tabs::{self, IndicatorPosition, Style};
 
     pub struct TabBar;
 
@@ -97,11 +97,20 @@ mod red {
                 background: None,
                 border_color: None,
                 border_width: 0,
+                border_radius: 0,
                 tab_label_background,
                 tab_label_border_color: Color::TRANSPARENT,
                 tab_label_border_width: 0,
+                tab_label_border_radius: 0,
                 icon_color: text_color,
                 text_color,
+                active_text_color: text_color,
+                indicator_color: None,
+                indicator_thickness: 0,
+                indicator_position: IndicatorPosition::Bottom,
+                badge_background: Color::BLACK.into(),
+                badge_text_color: Color::WHITE,
+                modified_color: text_color,
             }
         }
 
@@ -122,6 +131,7 @@ mod red {
                 tab_label_background,
                 icon_color: text_color,
                 text_color,
+                active_text_color: text_color,
                 .. self.active(is_selected)
             }
         }
@@ -130,7 +140,7 @@ mod red {
 
 mod blue {
     use iced::{Background, Color};
-    use iced_aw::tabs::{self, Style};
+    use iced_aw::tabs::{self, IndicatorPosition, Style};
 
     pub struct TabBar;
 
@@ -175,11 +185,20 @@ mod blue {
                 background: None,
                 border_color: None,
                 border_width: 0,
+                border_radius: 0,
                 tab_label_background,
                 tab_label_border_color,
                 tab_label_border_width: 1,
+                tab_label_border_radius: 10,
                 icon_color: text_color,
                 text_color,
+                active_text_color: text_color,
+                indicator_color: None,
+                indicator_thickness: 0,
+                indicator_position: IndicatorPosition::Bottom,
+                badge_background: Background::Color([1.0, 0.5, 0.0].into()),
+                badge_text_color: Color::WHITE,
+                modified_color: text_color,
             }
         }
 
@@ -207,7 +226,7 @@ mod blue {
 
 mod green {
     use iced::{Color};
-    use iced_aw::tabs::{self, Style};
+    use iced_aw::tabs::{self, IndicatorPosition, Style};
 
     pub struct TabBar;
 
@@ -242,11 +261,20 @@ mod green {
                 background: None,
                 border_color: None,
                 border_width: 0,
+                border_radius: 0,
                 tab_label_background: Color::WHITE.into(),
                 tab_label_border_color: color,
                 tab_label_border_width: 1,
+                tab_label_border_radius: 0,
                 icon_color: color,
                 text_color: color,
+                active_text_color: color,
+                indicator_color: Some([0.0, 0.5, 0.0].into()),
+                indicator_thickness: 3,
+                indicator_position: IndicatorPosition::Bottom,
+                badge_background: color.into(),
+                badge_text_color: Color::WHITE,
+                modified_color: color,
             }            
         }
 
@@ -268,6 +296,7 @@ mod green {
                 tab_label_border_color: color,
                 icon_color: color,
                 text_color: color,
+                active_text_color: color,
                 .. self.active(is_selected)
             }
         }
@@ -276,7 +305,7 @@ mod green {
 
 mod purple {
     use iced::{Color};
-    use iced_aw::tabs::{self, Style};
+    use iced_aw::tabs::{self, IndicatorPosition, Style};
 
     pub struct TabBar;
 
@@ -313,11 +342,20 @@ mod purple {
                 background: None,
                 border_color: None,
                 border_width: 0,
+                border_radius: 0,
                 tab_label_background: Color::WHITE.into(),
                 tab_label_border_color: Color::TRANSPARENT,
                 tab_label_border_width: 0,
+                tab_label_border_radius: 0,
                 icon_color: text_color,
                 text_color,
+                active_text_color: text_color,
+                indicator_color: None,
+                indicator_thickness: 0,
+                indicator_position: IndicatorPosition::Bottom,
+                badge_background: Color::from_rgb(0.7, 0.0, 1.0).into(),
+                badge_text_color: Color::WHITE,
+                modified_color: text_color,
             }
         }
 
@@ -336,6 +374,7 @@ mod purple {
             Style {
                 icon_color: text_color,
                 text_color,
+                active_text_color: text_color,
                 .. self.active(is_selected)
             }
         }
@@ -1,5 +1,5 @@
-use iced::{Column, Element, Sandbox, Settings, Text};
-use iced_aw::{Tabs, TabLabel};
+use iced::{Element, Length, Sandbox, Settings, Text};
+use iced_aw::{TabBadge, Tabs, TabLabel, tab_bar};
 
 fn main() -> iced::Result {
     TabBarExample::run(Settings::default())
@@ -12,6 +12,7 @@ enum Message {
 
 struct TabBarExample {
     active_tab: usize,
+    tab_bar_state: tab_bar::State,
 }
 
 impl Sandbox for TabBarExample {
@@ -21,6 +22,7 @@ impl Sandbox for TabBarExample {
     fn new() -> Self {
         TabBarExample {
             active_tab: 0,
+            tab_bar_state: tab_bar::State::new(),
         }
     }
 
@@ -37,19 +39,22 @@ impl Sandbox for TabBarExample {
     }
     
     fn view(&mut self) -> Element<Message> {
-        Tabs::new(self.active_tab, Message::TabSelected)
+        Tabs::new(&mut self.tab_bar_state, self.active_tab, Message::TabSelected)
             .push(
                 TabLabel::Text(String::from("One")),
                 Text::new("This is tab one")
             )
             .push(
-                TabLabel::Text(String::from("Two")),
+                TabLabel::Text(String::from("Two")).badge(TabBadge::Count(3)),
                 Text::new("This is tab two")
             )
             .push(
                 TabLabel::Text(String::from("Three")),
                 Text::new("This is tab three")
             )
+            .tab_modified(2, true)
+            .tab_width(Length::Shrink)
+            .overflow_menu(true)
             .into()
     }
 }
\ No newline at end of file
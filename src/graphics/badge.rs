@@ -43,6 +43,11 @@ where
         //  x
         let border_radius = style.border_radius
             .unwrap_or_else(|| (bounds.height as f32 / BORDER_RADIUS_RATIO));
+        let shadow = style.shadow.as_ref().map_or(
+            Primitive::None,
+            |shadow| super::shadow::draw_shadow(bounds, border_radius, shadow)
+        );
+
         let background = Primitive::Quad {
             bounds,
             background: style.background,
@@ -65,7 +70,7 @@ where
 
         (
             Primitive::Group {
-                primitives: vec![background, content],
+                primitives: vec![shadow, background, content],
             },
             mouse_interaction,
         )
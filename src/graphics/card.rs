@@ -1,10 +1,11 @@
 //! Displays a [`Card`](Card).
 //! 
 //! *This API requires the following crate features to be activated: card*
-use iced_graphics::{Backend, Color, Defaults, HorizontalAlignment, Primitive, Rectangle, Renderer, VerticalAlignment, backend, defaults};
+use iced_graphics::{Backend, Color, Defaults, HorizontalAlignment, Point, Primitive, Rectangle, Renderer, Vector, VerticalAlignment, backend, defaults};
 use iced_native::mouse;
 
 use crate::native::card;
+pub use card::{MediaFit, MediaPosition, ScrollState, Scrollbar, State};
 pub use crate::style::card::{Style, StyleSheet};
 
 /// A card consisting of a head, body and optional foot.
@@ -33,14 +34,42 @@ where
         head: &iced_native::Element<'_, Message, Self>,
         body: &iced_native::Element<'_, Message, Self>,
         foot: &Option<iced_native::Element<'_, Message, Self>>,
+        media: &Option<iced_native::Element<'_, Message, Self>>,
+        head_actions: &[char],
+        expanded: Option<bool>,
+        pressed: Option<bool>,
+        selected: bool,
+        scrollbar: Option<card::Scrollbar>,
         style_sheet: &Self::Style,
         viewport: &iced_graphics::Rectangle,
     ) -> Self::Output {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let style = style_sheet.active();
+        let is_mouse_over = bounds.contains(cursor_position);
 
-        let mouse_interaction = mouse::Interaction::default();
+        let mut style = match pressed {
+            Some(true) => style_sheet.pressed(),
+            Some(false) if is_mouse_over => style_sheet.hovered(),
+            _ => style_sheet.active(),
+        };
+
+        // A selected card keeps its border in every state.
+        if selected {
+            let selected_style = style_sheet.selected();
+            style.border_width = selected_style.border_width;
+            style.border_color = selected_style.border_color;
+        }
+
+        let mouse_interaction = if pressed.is_some() && is_mouse_over {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        let shadow = style.shadow.as_ref().map_or(
+            Primitive::None,
+            |shadow| super::shadow::draw_shadow(bounds, style.border_radius, shadow)
+        );
 
         let background = Primitive::Quad {
             bounds,
@@ -60,10 +89,19 @@ where
 
         let head_layout = children.next().unwrap();
         let mut head_children = head_layout.children();
+        let is_media_above_head = media.is_some()
+            && expanded != Some(false)
+            && layout.children().nth(3)
+                .map_or(false, |media_layout| media_layout.bounds().y < head_layout.bounds().y);
         let head_background = Primitive::Quad {
             bounds: head_layout.bounds(),
             background: style.head_background,
-            border_radius: style.border_radius as u16, // TODO: same,
+            // The media takes over the rounded corners at the top.
+            border_radius: if is_media_above_head {
+                0
+            } else {
+                style.border_radius as u16 // TODO: same
+            },
             border_width: 0,
             border_color: Color::TRANSPARENT,
         };
@@ -80,6 +118,69 @@ where
             viewport
         );
 
+        let mut mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+
+        let actions: Vec<Primitive> = head_actions.iter()
+            .zip(head_children.by_ref())
+            .map(|(icon, action_layout)| {
+                let action_bounds = action_layout.bounds();
+                let is_mouse_over_action = action_bounds.contains(cursor_position);
+
+                if is_mouse_over_action {
+                    mouse_interaction = mouse_interaction.max(mouse::Interaction::Pointer);
+                }
+
+                Primitive::Text {
+                    content: icon.to_string(),
+                    font: super::icons::ICON_FONT,
+                    size: action_bounds.height
+                        + if is_mouse_over_action { 5.0 } else { 0.0 },
+                    bounds: Rectangle {
+                        x: action_bounds.center_x(),
+                        y: action_bounds.center_y(),
+                        .. action_bounds
+                    },
+                    color: style.head_text_color,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                }
+            })
+            .collect();
+        let actions = Primitive::Group { primitives: actions };
+
+        let (toggle, new_mouse_interaction) = match expanded {
+            Some(is_expanded) => {
+                let toggle_bounds = head_children.next().unwrap().bounds();
+
+                (
+                    Primitive::Text {
+                        content: if is_expanded {
+                            super::icons::Icon::ChevronUp.into()
+                        } else {
+                            super::icons::Icon::ChevronDown.into()
+                        },
+                        font: super::icons::ICON_FONT,
+                        size: toggle_bounds.height,
+                        bounds: Rectangle {
+                            x: toggle_bounds.center_x(),
+                            y: toggle_bounds.center_y(),
+                            .. toggle_bounds
+                        },
+                        color: style.head_text_color,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    },
+                    // The whole head toggles the card.
+                    if head_layout.bounds().contains(cursor_position) {
+                        mouse::Interaction::Pointer
+                    } else {
+                        mouse::Interaction::default()
+                    }
+                )
+            },
+            None => (Primitive::None, mouse::Interaction::default()),
+        };
+
         let mouse_interaction = mouse_interaction.max(new_mouse_interaction);
 
         let (close, new_mouse_interaction) = head_children.next().map_or(
@@ -115,6 +216,25 @@ where
 
         let mouse_interaction = mouse_interaction.max(new_mouse_interaction);
 
+        // A collapsed card only shows its head.
+        if expanded == Some(false) {
+            return (
+                Primitive::Group {
+                    primitives: vec![
+                        shadow,
+                        background,
+                        head_background,
+                        border,
+                        head,
+                        actions,
+                        toggle,
+                        close,
+                    ]
+                },
+                mouse_interaction,
+            );
+        }
+
         let body_layout = children.next().unwrap();
         let mut body_children = body_layout.children();
         let body_background = Primitive::Quad {
@@ -125,17 +245,73 @@ where
             border_color: Color::TRANSPARENT,
         };
 
-        let (body, new_mouse_interaction) = body.draw(
-            self,
-            &Defaults {
-                text: defaults::Text {
-                    color: style.body_text_color
-                }
+        let body_defaults = Defaults {
+            text: defaults::Text {
+                color: style.body_text_color
+            }
+        };
+
+        let (body, new_mouse_interaction) = match scrollbar {
+            Some(scrollbar) => {
+                let body_bounds = body_layout.bounds();
+                let is_mouse_over_scrollbar = scrollbar.bounds.contains(cursor_position);
+
+                let (content, new_mouse_interaction) = body.draw(
+                    self,
+                    &body_defaults,
+                    body_children.next().unwrap(),
+                    if body_bounds.contains(cursor_position) && !is_mouse_over_scrollbar {
+                        Point::new(cursor_position.x, cursor_position.y + scrollbar.offset)
+                    } else {
+                        Point::new(-1.0, -1.0)
+                    },
+                    &Rectangle {
+                        y: body_bounds.y + scrollbar.offset,
+                        .. body_bounds
+                    },
+                );
+
+                let content = Primitive::Clip {
+                    bounds: body_bounds,
+                    offset: Vector::new(0, scrollbar.offset as u32),
+                    content: Box::new(content),
+                };
+
+                let scrollbar_background = Primitive::Quad {
+                    bounds: scrollbar.bounds,
+                    background: style.scrollbar_background,
+                    border_radius: style.scrollbar_border_radius as u16, // TODO: same
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                };
+
+                let scroller = Primitive::Quad {
+                    bounds: scrollbar.scroller,
+                    background: style.scroller_color.into(),
+                    border_radius: style.scrollbar_border_radius as u16, // TODO: same
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                };
+
+                (
+                    Primitive::Group {
+                        primitives: vec![content, scrollbar_background, scroller]
+                    },
+                    if is_mouse_over_scrollbar || scrollbar.is_grabbed {
+                        mouse::Interaction::Idle
+                    } else {
+                        new_mouse_interaction
+                    }
+                )
             },
-            body_children.next().unwrap(),
-            cursor_position,
-            viewport,
-        );
+            None => body.draw(
+                self,
+                &body_defaults,
+                body_children.next().unwrap(),
+                cursor_position,
+                viewport,
+            ),
+        };
 
         let mouse_interaction = mouse_interaction.max(new_mouse_interaction);
 
@@ -167,15 +343,108 @@ where
 
         let mouse_interaction = mouse_interaction.max(new_mouse_interaction);
 
+        let (media, new_mouse_interaction) = match (media, children.next()) {
+            (Some(media), Some(media_layout)) => {
+                let media_bounds = media_layout.bounds();
+
+                // Only the top corners of a media area above the head are
+                // rounded.
+                let radius = if is_media_above_head {
+                    style.border_radius
+                        .min(media_bounds.width / 2.0)
+                        .min(media_bounds.height)
+                } else {
+                    0.0
+                };
+                let lower_bounds = Rectangle {
+                    y: media_bounds.y + radius,
+                    height: media_bounds.height - radius,
+                    .. media_bounds
+                };
+
+                let media_background = Primitive::Group {
+                    primitives: vec![
+                        Primitive::Quad {
+                            bounds: Rectangle {
+                                height: (2.0 * radius).min(media_bounds.height),
+                                .. media_bounds
+                            },
+                            background: style.media_background,
+                            border_radius: radius as u16, // TODO: same
+                            border_width: 0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        Primitive::Quad {
+                            bounds: lower_bounds,
+                            background: style.media_background,
+                            border_radius: 0,
+                            border_width: 0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                    ]
+                };
+
+                let (content, new_mouse_interaction) = media.draw(
+                    self,
+                    &body_defaults,
+                    media_layout.children().next().unwrap(),
+                    cursor_position,
+                    viewport,
+                );
+
+                // Clipping is rectangular, so the media is kept out of the
+                // rounded corners by clipping it below and in between them.
+                let clip = |bounds: Rectangle, content: Primitive| Primitive::Clip {
+                    bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(content),
+                };
+                let media = if radius > 0.0 {
+                    let upper_bounds = Rectangle {
+                        x: media_bounds.x + radius,
+                        width: media_bounds.width - 2.0 * radius,
+                        height: radius,
+                        .. media_bounds
+                    };
+
+                    Primitive::Group {
+                        primitives: vec![
+                            clip(upper_bounds, content.clone()),
+                            clip(lower_bounds, content),
+                        ]
+                    }
+                } else {
+                    clip(media_bounds, content)
+                };
+
+                (
+                    Primitive::Group {
+                        primitives: vec![
+                            media_background,
+                            media,
+                        ]
+                    },
+                    new_mouse_interaction,
+                )
+            },
+            _ => (Primitive::None, mouse::Interaction::default()),
+        };
+
+        let mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+
         (
             Primitive::Group {
                 primitives: vec![
+                    shadow,
                     background,
                     head_background,
                     body_background,
                     foot_background,
+                    media,
                     border,
                     head,
+                    actions,
+                    toggle,
                     close,
                     body,
                     foot,
@@ -0,0 +1,29 @@
+//! Ready-made dialogs showing a [`Card`](crate::native::card::Card) in a
+//! [`Modal`](crate::native::modal::Modal).
+//! 
+//! *This API requires the following crate features to be activated: dialog*
+use iced_graphics::Renderer;
+
+use crate::native::dialog;
+pub use dialog::State;
+
+/// An alert dialog showing a message that has to be acknowledged with an OK
+/// button.
+/// 
+/// This is an alias of an `iced_native` Alert with an `iced_wgpu::Renderer`.
+pub type Alert<'a, Message, Backend> =
+    dialog::Alert<'a, Message, Renderer<Backend>>;
+
+/// A confirm dialog asking to either accept or cancel an action with an OK
+/// and a Cancel button.
+/// 
+/// This is an alias of an `iced_native` Confirm with an `iced_wgpu::Renderer`.
+pub type Confirm<'a, Message, Backend> =
+    dialog::Confirm<'a, Message, Renderer<Backend>>;
+
+/// A prompt dialog asking for a text with a text input, an OK and a Cancel
+/// button.
+/// 
+/// This is an alias of an `iced_native` Prompt with an `iced_wgpu::Renderer`.
+pub type Prompt<'a, Message, Backend> =
+    dialog::Prompt<'a, Message, Renderer<Backend>>;
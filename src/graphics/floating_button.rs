@@ -1,8 +1,9 @@
 //! TODO
 
-use iced_graphics::{Backend, Point, Rectangle, Renderer, backend};
+use iced_graphics::{Backend, Point, Primitive, Rectangle, Renderer, backend};
 
 use crate::native::floating_button;
+use crate::style::shadow::Shadow;
 //pub use crate::style::floating_button::{Style, StyleSheet};
 pub use floating_button::{Anchor, Offset};
 
@@ -23,8 +24,28 @@ where
         //style_sheet: &Self::Style,
         layout: iced_native::Layout<'_>,
         floating: &iced_native::Element<'_, Message, Self>,
+        shadow: Option<(&Shadow, f32)>,
         viewport: &Rectangle,
     ) -> Self::Output {
-        floating.draw(self, &defaults, layout, cursor_position, viewport)
+        let (floating, mouse_interaction) = floating.draw(
+            self,
+            &defaults,
+            layout,
+            cursor_position,
+            viewport
+        );
+
+        match shadow {
+            Some((shadow, border_radius)) => (
+                Primitive::Group {
+                    primitives: vec![
+                        super::shadow::draw_shadow(layout.bounds(), border_radius, shadow),
+                        floating,
+                    ]
+                },
+                mouse_interaction,
+            ),
+            None => (floating, mouse_interaction),
+        }
     }
 }
\ No newline at end of file
@@ -4,6 +4,18 @@
 /// Bootstrap icons
 #[derive(Copy, Clone, Debug, Hash)]
 pub enum Icon {
+	/// chevron-down
+	ChevronDown,
+	/// chevron-left
+	ChevronLeft,
+	/// chevron-right
+	ChevronRight,
+	/// chevron-up
+	ChevronUp,
+	/// plus
+	Plus,
+	/// three-dots-vertical
+	ThreeDotsVertical,
 	/// x
 	X,
 }
@@ -11,6 +23,12 @@ pub enum Icon {
 /// Converts an icon into a char.
 pub fn icon_to_char(icon: Icon) -> char {
 	match icon {
+		Icon::ChevronDown => '\u{f270}',
+		Icon::ChevronLeft => '\u{f272}',
+		Icon::ChevronRight => '\u{f273}',
+		Icon::ChevronUp => '\u{f274}',
+		Icon::Plus => '\u{f4a5}',
+		Icon::ThreeDotsVertical => '\u{f561}',
 		Icon::X => '\u{f5ae}',
 	}
 }
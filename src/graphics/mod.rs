@@ -2,6 +2,14 @@
 
 pub mod icons;
 
+#[cfg(any(
+    feature = "badge",
+    feature = "card",
+    feature = "floating_button",
+    feature = "modal",
+))]
+pub(crate) mod shadow;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
@@ -12,6 +20,9 @@ pub mod card;
 #[cfg(feature = "card")]
 pub use card::Card;
 
+#[cfg(feature = "dialog")]
+pub mod dialog;
+
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
@@ -2,11 +2,12 @@
 //! 
 //! *This API requires the following crate features to be activated: badge*
 
-use iced_graphics::{Backend, Color, Primitive, Renderer};
+use iced_graphics::{Backend, Background, Color, Point, Primitive, Renderer};
 
 use crate::native::modal;
-pub use crate::native::modal::State;
+pub use crate::native::modal::{Animation, Easing, Focus, Placement, State};
 pub use crate::style::modal::{Style, StyleSheet};
+use crate::style::shadow::Shadow;
 
 /// A modal content as an overlay.
 /// 
@@ -27,30 +28,66 @@ where
         style_sheet: &Self::Style,
         modal: &iced_native::Element<'_, Message, Self>,
         layout: iced_native::Layout<'_>,
+        progress: f32,
+        below: Option<(&iced_native::overlay::Element<'_, Message, Self>, iced_native::Layout<'_>)>,
     ) -> Self::Output {
         let bounds = layout.bounds();
 
+        // The lower level of a stack is inactive and not hovered.
+        let below = below.map_or(
+            Primitive::None,
+            |(below, below_layout)| below.draw(
+                self,
+                defaults,
+                below_layout,
+                Point::new(-1.0, -1.0),
+            ).0
+        );
+
         let style = style_sheet.active();
 
+        // The backdrop and the shadow fade in with the open animation.
+        let fade = |color: Color| Color {
+            a: color.a * progress,
+            ..color
+        };
+
         let background = Primitive::Quad {
             bounds,
-            background: style.background,
+            background: match style.background {
+                Background::Color(color) => Background::Color(fade(color)),
+            },
             border_radius: 0,
             border_width: 0,
             border_color: Color::TRANSPARENT,
         };
 
+        // The shadow surrounds the content placed within the window.
+        let shadow = style.content_shadow.as_ref()
+            .zip(layout.children().next())
+            .map_or(
+                Primitive::None,
+                |(shadow, content_layout)| super::shadow::draw_shadow(
+                    content_layout.bounds(),
+                    style.content_border_radius,
+                    &Shadow {
+                        color: fade(shadow.color),
+                        ..*shadow
+                    },
+                )
+            );
+
         let (modal, mouse_interaction) = modal.draw(
             self,
             defaults,
-            layout,
+            layout.children().next().unwrap(),
             cursor_position,
             &bounds
         );
 
         (
             Primitive::Group {
-                primitives: vec![background, modal],
+                primitives: vec![below, background, shadow, modal],
             },
             mouse_interaction,
         )
@@ -0,0 +1,40 @@
+//! Draws the drop [`Shadow`](Shadow) of a widget.
+use iced_graphics::{Color, Primitive, Rectangle};
+
+use crate::style::shadow::Shadow;
+
+/// The number of quads layered on top of each other to draw a shadow.
+const LAYERS: u16 = 8;
+
+/// Draws the given [`Shadow`](Shadow) of a widget with the given bounds and
+/// border radius by layering translucent quads.
+/// 
+/// Every layer is a bit smaller than the previous one, so the shadow gets
+/// darker towards the widget.
+pub(crate) fn draw_shadow(bounds: Rectangle, border_radius: f32, shadow: &Shadow) -> Primitive {
+    let color = Color {
+        a: shadow.color.a / f32::from(LAYERS),
+        .. shadow.color
+    };
+
+    let primitives = (0..LAYERS)
+        .map(|layer| {
+            let spread = shadow.blur_radius * f32::from(LAYERS - layer) / f32::from(LAYERS);
+
+            Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + shadow.offset_x - spread,
+                    y: bounds.y + shadow.offset_y - spread,
+                    width: bounds.width + 2.0 * spread,
+                    height: bounds.height + 2.0 * spread,
+                },
+                background: color.into(),
+                border_radius: (border_radius + spread) as u16, // TODO: will be changed to f32
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            }
+        })
+        .collect();
+
+    Primitive::Group { primitives }
+}
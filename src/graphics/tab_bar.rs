@@ -4,18 +4,47 @@
 //! to use the [`Tabs`](super::tabs) widget instead.
 //! 
 //! *This API requires the following crate features to be activated: tab_bar*
-use iced_graphics::{Backend, Color, Primitive, Rectangle, Renderer, backend};
+use iced_graphics::{
+    Backend, Color, Defaults, Primitive, Rectangle, Renderer, Size, Vector,
+    backend, defaults
+};
 use iced_native::{Font, HorizontalAlignment, Layout, Point, VerticalAlignment, mouse};
-pub use tab_bar::tab_label::TabLabel;
+pub use tab_bar::{
+    DraggedTab, State, TabStatus, dock::{DockGroup, DropTarget, Edge, TabMove},
+    orientation::Orientation, tab::Tab, tab_badge::TabBadge,
+    tab_label::TabLabel
+};
 
 use crate::native::tab_bar;
-pub use crate::style::tab_bar::{Style, StyleSheet};
+pub use crate::style::tab_bar::{
+    DropZoneStyle, IndicatorPosition, MenuStyle, Style, StyleSheet
+};
+use super::icons::{ICON_FONT, Icon};
+
+/// The thickness of the indicator of the position a dragged tab would be
+/// dropped at.
+const DROP_INDICATOR_THICKNESS: f32 = 2.0;
+
+/// The text size of the count of a [`TabBadge`](TabBadge).
+const BADGE_TEXT_SIZE: f32 = 12.0;
+
+/// The padding around the count of a [`TabBadge`](TabBadge).
+const BADGE_PADDING: f32 = 2.0;
+
+/// The size of a [`TabBadge::Dot`](TabBadge::Dot).
+const BADGE_DOT_SIZE: f32 = 8.0;
+
+/// The distance of a [`TabBadge`](TabBadge) to the border of its tab.
+const BADGE_MARGIN: f32 = 2.0;
+
+/// The ellipsis appended to the text of a truncated tab.
+const ELLIPSIS: &str = "\u{2026}";
 
 /// A tab bar to show tabs.
 /// 
 /// This is an alias of an `iced_native` TabBar with an `iced_wgpu::Renderer`.
-pub type TabBar<Message, Backend> =
-    tab_bar::TabBar<Message, Renderer<Backend>>;
+pub type TabBar<'a, Message, Backend> =
+    tab_bar::TabBar<'a, Message, Renderer<Backend>>;
 
 impl<B> tab_bar::Renderer for Renderer<B>
 where
@@ -33,20 +62,39 @@ where
 
     const DEFAULT_SPACING: u16 = 0;
 
-    fn draw(
+    fn draw<Message>(
         &mut self,
         _defaults: &Self::Defaults,
         active_tab: usize,
-        tab_labels: &[TabLabel],
+        tabs: &[Tab<'_, Message, Self>],
+        tab_statuses: &[TabStatus],
         layout: Layout<'_>,
         cursor_position: Point,
         icon_font: Option<Font>,
         text_font: Option<Font>,
+        orientation: Orientation,
+        scroll_offset: f32,
+        dragged_tab: Option<DraggedTab>,
+        dock_drop_index: Option<usize>,
+        has_new_button: bool,
+        has_menu_button: bool,
         style_sheet: &Self::Style,
+        viewport: &Rectangle,
     ) -> Self::Output {
-        // TODO tab bar background
         let bounds = layout.bounds();
-        let children = layout.children();
+        let mut children = layout.children();
+        let tab_layouts: Vec<Layout<'_>> = children.by_ref().take(tabs.len()).collect();
+        let new_button = if has_new_button {
+            children.next().map(|layout| layout.bounds())
+        } else {
+            None
+        };
+        let menu_button = if has_menu_button {
+            children.next().map(|layout| layout.bounds())
+        } else {
+            None
+        };
+        let arrow_layouts: Vec<Layout<'_>> = children.collect();
         let is_mouse_over = bounds.contains(cursor_position);
         let style = if is_mouse_over {
             style_sheet.hovered(false)
@@ -60,26 +108,155 @@ where
             Primitive::Quad {
                 bounds,
                 background: style.background.unwrap_or(Color::TRANSPARENT.into()),
-                border_radius: 0,
+                border_radius: style.border_radius,
                 border_width: style.border_width,
                 border_color: style.border_color.unwrap_or(Color::TRANSPARENT),
             }
         );
 
-        primitives = tab_labels.iter()
-            .enumerate()
-            .zip(children)
-            .fold(
-                primitives,
-                |mut primitives, ((i, tab), layout)| {
-                    let (primitive, new_mouse_interaction) = draw_tab(
-                        tab,
-                        layout,
-                        style_sheet,
-                        i == active_tab,
+        let arrows = match arrow_layouts.as_slice() {
+            [back, forward] => Some((back.bounds(), forward.bounds())),
+            _ => None,
+        };
+
+        let strip = arrows.map(|(back, forward)| match orientation {
+            Orientation::Horizontal => Rectangle {
+                x: back.x + back.width,
+                width: forward.x - back.x - back.width,
+                .. bounds
+            },
+            Orientation::Vertical => Rectangle {
+                y: back.y + back.height,
+                height: forward.y - back.y - back.height,
+                .. bounds
+            },
+        });
+
+        let tabs_cursor_position = tab_bar::tabs_cursor_position(
+            strip,
+            scroll_offset,
+            orientation,
+            cursor_position,
+        );
+
+        let mut tab_primitives = Vec::with_capacity(tabs.len());
+        let mut pinned_primitives = Vec::new();
+        let tab_bounds: Vec<Rectangle> = tab_layouts.iter()
+            .map(|layout| layout.bounds())
+            .collect();
+
+        for ((i, tab), layout) in tabs.iter().enumerate().zip(tab_layouts) {
+            let status = tab_statuses.get(i).copied().unwrap_or_default();
+            let (primitive, new_mouse_interaction) = draw_tab(
+                self,
+                tab,
+                layout,
+                style_sheet,
+                i == active_tab,
+                status,
+                tabs_cursor_position,
+                icon_font.unwrap_or(B::ICON_FONT),
+                text_font.unwrap_or(Font::default()),
+                viewport,
+            );
+
+            if new_mouse_interaction > mouse_interaction {
+                mouse_interaction = new_mouse_interaction;
+            }
+
+            // Pinned tabs are not scrolled. Their place is kept empty to
+            // find the primitive of a tab by its index.
+            if status.is_pinned {
+                pinned_primitives.push(primitive);
+                tab_primitives.push(Primitive::None);
+            } else {
+                tab_primitives.push(primitive);
+            }
+        }
+
+        if let Some(dragged_tab) = dragged_tab {
+            // Dropping the tab next to itself would not change its position.
+            let is_moved = dragged_tab.drop_index != dragged_tab.index
+                && dragged_tab.drop_index != dragged_tab.index + 1;
+
+            if let Some(indicator) = Some(dragged_tab.drop_index)
+                .filter(|_| is_moved)
+                .and_then(|drop_index| draw_drop_indicator(
+                    &tab_bounds,
+                    tab_statuses,
+                    drop_index,
+                    orientation,
+                    style.icon_color,
+                ))
+            {
+                tab_primitives.push(indicator);
+            }
+
+            if let Some(ghost) = tab_primitives.get(dragged_tab.index).cloned() {
+                tab_primitives.push(Primitive::Translate {
+                    translation: match orientation {
+                        Orientation::Horizontal => Vector::new(dragged_tab.offset, 0.0),
+                        Orientation::Vertical => Vector::new(0.0, dragged_tab.offset),
+                    },
+                    content: Box::new(ghost),
+                });
+            }
+
+            mouse_interaction = mouse::Interaction::Grabbing;
+        }
+
+        if let Some(drop_index) = dock_drop_index {
+            let indicator = draw_drop_indicator(
+                &tab_bounds,
+                tab_statuses,
+                drop_index,
+                orientation,
+                style.icon_color,
+            );
+
+            // An empty TabBar highlights its whole strip instead.
+            tab_primitives.push(indicator.unwrap_or_else(|| {
+                let drop_zone = style_sheet.drop_zone();
+
+                Primitive::Quad {
+                    bounds,
+                    background: drop_zone.background,
+                    border_radius: style.border_radius,
+                    border_width: drop_zone.border_width,
+                    border_color: drop_zone.border_color,
+                }
+            }));
+
+            mouse_interaction = mouse::Interaction::Grabbing;
+        }
+
+        match (strip, arrows) {
+            (Some(strip), Some((back, forward))) => {
+                let offset = match orientation {
+                    Orientation::Horizontal => Vector::new(scroll_offset as u32, 0),
+                    Orientation::Vertical => Vector::new(0, scroll_offset as u32),
+                };
+
+                primitives.push(Primitive::Clip {
+                    bounds: strip,
+                    offset,
+                    content: Box::new(Primitive::Group {
+                        primitives: tab_primitives,
+                    }),
+                });
+                primitives.extend(pinned_primitives);
+
+                let (back_icon, forward_icon) = match orientation {
+                    Orientation::Horizontal => (Icon::ChevronLeft, Icon::ChevronRight),
+                    Orientation::Vertical => (Icon::ChevronUp, Icon::ChevronDown),
+                };
+
+                for (arrow_bounds, icon) in vec![(back, back_icon), (forward, forward_icon)] {
+                    let (primitive, new_mouse_interaction) = draw_icon_button(
+                        arrow_bounds,
+                        icon,
+                        style.icon_color,
                         cursor_position,
-                        icon_font.unwrap_or(B::ICON_FONT),
-                        text_font.unwrap_or(Font::default()),
                     );
 
                     if new_mouse_interaction > mouse_interaction {
@@ -87,10 +264,32 @@ where
                     }
 
                     primitives.push(primitive);
-                    primitives
                 }
+            },
+            _ => {
+                primitives.extend(pinned_primitives);
+                primitives.extend(tab_primitives);
+            },
+        }
+
+        let buttons = new_button.map(|bounds| (bounds, Icon::Plus)).into_iter()
+            .chain(menu_button.map(|bounds| (bounds, Icon::ThreeDotsVertical)));
+
+        for (button_bounds, icon) in buttons {
+            let (primitive, new_mouse_interaction) = draw_icon_button(
+                button_bounds,
+                icon,
+                style.icon_color,
+                cursor_position,
             );
 
+            if new_mouse_interaction > mouse_interaction {
+                mouse_interaction = new_mouse_interaction;
+            }
+
+            primitives.push(primitive);
+        }
+
         (
             Primitive::Group {
                 primitives: primitives,
@@ -98,23 +297,278 @@ where
             mouse_interaction,
         )
     }
+
+    fn draw_menu<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        active_tab: usize,
+        tabs: &[Tab<'_, Message, Self>],
+        tab_statuses: &[TabStatus],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        icon_font: Option<Font>,
+        text_font: Option<Font>,
+        scroll_offset: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let bounds = layout.bounds();
+        let style = style_sheet.menu();
+        let icon_font = icon_font.unwrap_or(B::ICON_FONT);
+        let text_font = text_font.unwrap_or(Font::default());
+
+        // The entries are drawn with the offset of the scroll position.
+        let entries_cursor_position = if bounds.contains(cursor_position) {
+            Point::new(cursor_position.x, cursor_position.y + scroll_offset)
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let mut entry_primitives = Vec::with_capacity(tabs.len());
+
+        for ((i, tab), entry_layout) in tabs.iter().enumerate().zip(layout.children()) {
+            let entry_bounds = entry_layout.bounds();
+            let is_disabled = tab_statuses.get(i)
+                .map_or(false, |status| status.is_disabled);
+            let is_mouse_over = !is_disabled
+                && entry_bounds.contains(entries_cursor_position);
+
+            let (icon_color, text_color) = if is_disabled {
+                (
+                    Color { a: style.icon_color.a / 2.0, .. style.icon_color },
+                    Color { a: style.text_color.a / 2.0, .. style.text_color },
+                )
+            } else {
+                (style.icon_color, style.text_color)
+            };
+
+            let entry_background = if i == active_tab {
+                Some(style.active_background)
+            } else if is_mouse_over {
+                Some(style.hovered_background)
+            } else {
+                None
+            };
+
+            if let Some(entry_background) = entry_background {
+                entry_primitives.push(Primitive::Quad {
+                    bounds: entry_bounds,
+                    background: entry_background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if is_mouse_over {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            match tab {
+                Tab::Label(tab_label) => entry_primitives.push(draw_tab_label(
+                    self,
+                    tab_label,
+                    entry_layout,
+                    icon_color,
+                    text_color,
+                    icon_font,
+                    text_font,
+                )),
+                Tab::Element(element) => {
+                    let (primitive, new_mouse_interaction) = element.draw(
+                        self,
+                        &Defaults {
+                            text: defaults::Text {
+                                color: text_color,
+                            }
+                        },
+                        entry_layout.children().next().unwrap(),
+                        entries_cursor_position,
+                        &bounds,
+                    );
+
+                    mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+                    entry_primitives.push(primitive);
+                },
+            }
+        }
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    background,
+                    Primitive::Clip {
+                        bounds,
+                        offset: Vector::new(0, scroll_offset as u32),
+                        content: Box::new(Primitive::Group {
+                            primitives: entry_primitives,
+                        }),
+                    },
+                ],
+            },
+            mouse_interaction,
+        )
+    }
+
+    fn draw_tooltip(
+        &mut self,
+        _defaults: &Self::Defaults,
+        text: &str,
+        layout: Layout<'_>,
+        text_size: u16,
+        text_font: Option<Font>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let bounds = layout.bounds();
+
+        // The tooltip only exists for truncated labels and always holds
+        // their text.
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let style = style_sheet.menu();
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds,
+                        background: style.background,
+                        border_radius: style.border_radius,
+                        border_width: style.border_width,
+                        border_color: style.border_color,
+                    },
+                    Primitive::Text {
+                        content: text.to_owned(),
+                        font: text_font.unwrap_or(Font::default()),
+                        size: f32::from(text_size),
+                        bounds: text_bounds,
+                        color: style.text_color,
+                        horizontal_alignment: HorizontalAlignment::Left,
+                        vertical_alignment: VerticalAlignment::Top,
+                    },
+                ],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Draws the indicator of the position a dragged tab would be dropped at.
+fn draw_drop_indicator(
+    tab_bounds: &[Rectangle],
+    tab_statuses: &[TabStatus],
+    drop_index: usize,
+    orientation: Orientation,
+    color: Color,
+) -> Option<Primitive> {
+    let (bounds, at_end) = match tab_bounds.get(drop_index) {
+        Some(bounds) => (*bounds, false),
+        // The pinned tabs are placed in front of the last unpinned tab.
+        None => (
+            *tab_bounds.iter()
+                .zip(tab_statuses)
+                .filter(|(_, status)| !status.is_pinned)
+                .map(|(bounds, _)| bounds)
+                .last()?,
+            true,
+        ),
+    };
+
+    let indicator = match orientation {
+        Orientation::Horizontal => Rectangle {
+            x: if at_end {
+                bounds.x + bounds.width - DROP_INDICATOR_THICKNESS
+            } else {
+                bounds.x
+            },
+            width: DROP_INDICATOR_THICKNESS,
+            .. bounds
+        },
+        Orientation::Vertical => Rectangle {
+            y: if at_end {
+                bounds.y + bounds.height - DROP_INDICATOR_THICKNESS
+            } else {
+                bounds.y
+            },
+            height: DROP_INDICATOR_THICKNESS,
+            .. bounds
+        },
+    };
+
+    Some(Primitive::Quad {
+        bounds: indicator,
+        background: color.into(),
+        border_radius: 0,
+        border_width: 0,
+        border_color: Color::TRANSPARENT,
+    })
+}
+
+/// Draws a button of the tab bar showing the given icon, like the arrows for
+/// scrolling the tabs.
+fn draw_icon_button(
+    bounds: Rectangle,
+    icon: Icon,
+    color: Color,
+    cursor_position: Point,
+) -> (Primitive, mouse::Interaction) {
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    (
+        Primitive::Text {
+            content: icon.into(),
+            font: ICON_FONT,
+            size: bounds.width.min(bounds.height)
+                + if is_mouse_over { 5.0 } else { 0.0 },
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                .. bounds
+            },
+            color,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        },
+        if is_mouse_over {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    )
 }
 
 /// Draws a tab.
-fn draw_tab(
-    tab: &TabLabel,
+fn draw_tab<Message, B>(
+    renderer: &mut Renderer<B>,
+    tab: &Tab<'_, Message, Renderer<B>>,
     layout: Layout<'_>,
     style_sheet: &Box<dyn StyleSheet>,
     is_selected: bool,
+    status: TabStatus,
     cursor_position: iced_native::Point,
     icon_font: Font,
     text_font: Font,
-) -> (Primitive, mouse::Interaction) {
+    viewport: &Rectangle,
+) -> (Primitive, mouse::Interaction)
+where
+    B: Backend + backend::Text,
+{
     let is_mouse_over = layout.bounds().contains(cursor_position);
-    let style = if is_mouse_over {
-        style_sheet.hovered(is_selected)
-    } else {
-        style_sheet.active(is_selected)
+    let style = match (status.is_disabled, status.is_pinned, is_mouse_over) {
+        (true, _, _) => style_sheet.disabled(is_selected),
+        (false, true, true) => style_sheet.pinned_hovered(is_selected),
+        (false, true, false) => style_sheet.pinned(is_selected),
+        (false, false, true) => style_sheet.hovered(is_selected),
+        (false, false, false) => style_sheet.active(is_selected),
     };
 
     let bounds = layout.bounds();
@@ -125,17 +579,53 @@ fn draw_tab(
     let background = Primitive::Quad {
         bounds,
         background: style.tab_label_background,
-        border_radius: 0,
+        border_radius: style.tab_label_border_radius,
         border_width: style.tab_label_border_width,
         border_color: style.tab_label_border_color,
     };
 
+    let indicator = match style.indicator_color {
+        Some(color) if is_selected && style.indicator_thickness > 0 => {
+            draw_indicator(bounds, color, &style)
+        },
+        _ => Primitive::None,
+    };
+
+    let text_color = if is_selected {
+        style.active_text_color
+    } else {
+        style.text_color
+    };
+
     let cross = children.next().map_or(
         Primitive::None,
         |cross_layout| {
             let cross_bounds = cross_layout.bounds();
             let is_mouse_over_cross = cross_bounds.contains(cursor_position);
 
+            // The modified indicator replaces the close icon until the tab
+            // is hovered.
+            if status.is_modified && !(status.is_closable && is_mouse_over) {
+                let diameter = cross_bounds.width.min(cross_bounds.height) / 2.0;
+
+                return Primitive::Quad {
+                    bounds: Rectangle {
+                        x: cross_bounds.center_x() - diameter / 2.0,
+                        y: cross_bounds.center_y() - diameter / 2.0,
+                        width: diameter,
+                        height: diameter,
+                    },
+                    background: style.modified_color.into(),
+                    border_radius: (diameter / 2.0) as u16,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                };
+            }
+
+            if !status.is_closable {
+                return Primitive::None;
+            }
+
             Primitive::Text {
                 content: super::icons::Icon::X.into(),
                 font: super::icons::ICON_FONT,
@@ -153,14 +643,155 @@ fn draw_tab(
         }
     );
 
-    let primitive = match tab {
+    let mut mouse_interaction = if is_mouse_over && !status.is_disabled {
+        mouse::Interaction::Pointer
+    } else {
+        mouse::Interaction::default()
+    };
+
+    let (label, badge) = match tab {
+        Tab::Label(tab_label) => (
+            draw_tab_label(
+                renderer,
+                &if status.is_pinned {
+                    tab_label.pinned_label()
+                } else {
+                    tab_label.clone()
+                },
+                label_layout,
+                style.icon_color,
+                text_color,
+                icon_font,
+                text_font,
+            ),
+            tab_label.get_badge().map_or(
+                Primitive::None,
+                |badge| draw_badge(renderer, badge, bounds, &style, text_font),
+            ),
+        ),
+        Tab::Element(element) => {
+            let (element, new_mouse_interaction) = element.draw(
+                renderer,
+                &Defaults {
+                    text: defaults::Text {
+                        color: text_color,
+                    }
+                },
+                label_layout_children.next().unwrap(),
+                cursor_position,
+                viewport,
+            );
+
+            mouse_interaction = mouse_interaction.max(new_mouse_interaction);
+
+            (element, Primitive::None)
+        },
+    };
+
+    let primitive = Primitive::Group {
+        primitives: vec![
+            background,
+            indicator,
+            label,
+            badge,
+            cross,
+        ]
+    };
+
+    (primitive, mouse_interaction)
+}
+
+/// Draws the indicator of the active tab at the side of the given bounds of
+/// the tab defined by the [`Style`](Style).
+fn draw_indicator(bounds: Rectangle, color: Color, style: &Style) -> Primitive {
+    let thickness = f32::from(style.indicator_thickness);
+
+    let indicator_bounds = match style.indicator_position {
+        IndicatorPosition::Top => Rectangle {
+            height: thickness,
+            .. bounds
+        },
+        IndicatorPosition::Bottom => Rectangle {
+            y: bounds.y + bounds.height - thickness,
+            height: thickness,
+            .. bounds
+        },
+        IndicatorPosition::Left => Rectangle {
+            width: thickness,
+            .. bounds
+        },
+        IndicatorPosition::Right => Rectangle {
+            x: bounds.x + bounds.width - thickness,
+            width: thickness,
+            .. bounds
+        },
+    };
+
+    Primitive::Quad {
+        bounds: indicator_bounds,
+        background: color.into(),
+        border_radius: 0,
+        border_width: 0,
+        border_color: Color::TRANSPARENT,
+    }
+}
+
+/// Draws the content of a [`TabLabel`](TabLabel).
+fn draw_tab_label<B>(
+    renderer: &Renderer<B>,
+    tab_label: &TabLabel,
+    layout: Layout<'_>,
+    icon_color: Color,
+    text_color: Color,
+    icon_font: Font,
+    text_font: Font,
+) -> Primitive
+where
+    B: Backend + backend::Text,
+{
+    let mut children = layout.children();
+
+    match tab_label {
         TabLabel::Icon(icon) => {
-            let icon_bounds = label_layout_children.next().unwrap().bounds();
+            let icon_bounds = children.next().unwrap().bounds();
+
+            Primitive::Text {
+                content: icon.to_string(),
+                font: icon_font,
+                size: icon_bounds.height,
+                bounds: Rectangle {
+                    x: icon_bounds.center_x(),
+                    y: icon_bounds.center_y(),
+                    .. icon_bounds
+                },
+                color: icon_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        },
+        TabLabel::Text(text) => {
+            let text_bounds = children.next().unwrap().bounds();
+
+            Primitive::Text {
+                content: truncate(renderer, text, text_bounds.height, text_font, text_bounds.width),
+                font: text_font,
+                size: text_bounds.height,
+                bounds: Rectangle {
+                    x: text_bounds.center_x(),
+                    y: text_bounds.center_y(),
+                    .. text_bounds
+                },
+                color: text_color,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            }
+        },
+        TabLabel::IconText(icon, text) => {
+            let icon_bounds = children.next().unwrap().bounds();
+            let text_bounds = children.next().unwrap().bounds();
 
             Primitive::Group {
                 primitives: vec![
-                    background,
-
                     Primitive::Text {
                         content: icon.to_string(),
                         font: icon_font,
@@ -170,88 +801,183 @@ fn draw_tab(
                             y: icon_bounds.center_y(),
                             .. icon_bounds
                         },
-                        color: style.icon_color,
+                        color: icon_color,
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
                     },
 
-                    cross,
-                ]
-            }
-        },
-        TabLabel::Text(text) => {
-            let text_bounds = label_layout_children.next().unwrap().bounds();
-
-            Primitive::Group {
-                primitives: vec![
-                    background,
-                    
                     Primitive::Text {
-                        content: text.to_string(),
+                        content: truncate(
+                            renderer,
+                            text,
+                            text_bounds.height,
+                            text_font,
+                            text_bounds.width,
+                        ),
                         font: text_font,
                         size: text_bounds.height,
                         bounds: Rectangle {
                             x: text_bounds.center_x(),
                             y: text_bounds.center_y(),
-                            .. text_bounds   
+                            .. text_bounds
                         },
-                        color: style.text_color,
+                        color: text_color,
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
                     },
-
-                    cross,
                 ]
             }
         },
-        TabLabel::IconText(icon, text) => {
-            let icon_bounds = label_layout_children.next().unwrap().bounds();
-            let text_bounds = label_layout_children.next().unwrap().bounds();
+        TabLabel::WithBadge(label, _badge) => {
+            draw_tab_label(renderer, label, layout, icon_color, text_color, icon_font, text_font)
+        },
+    }
+}
+
+/// Truncates the given text with an ellipsis if it is wider than the given
+/// width.
+fn truncate<B>(
+    renderer: &Renderer<B>,
+    text: &str,
+    size: f32,
+    font: Font,
+    width: f32,
+) -> String
+where
+    B: Backend + backend::Text,
+{
+    truncate_with(text, width, |content| {
+        renderer.backend().measure(content, size, font, Size::INFINITY).0
+    })
+}
+
+/// Truncates the given text with an ellipsis if it is wider than the given
+/// width measured by the given function.
+fn truncate_with(text: &str, width: f32, measure: impl Fn(&str) -> f32) -> String {
+    if text.is_empty() || measure(text) <= width.ceil() {
+        return text.to_owned();
+    }
+
+    // Search the longest prefix that fits along with the ellipsis. The
+    // prefix of n characters ends where the character n starts.
+    let starts: Vec<usize> = text.char_indices().map(|(start, _)| start).collect();
+    let truncated = |chars: usize| format!("{}{}", text[..starts[chars]].trim_end(), ELLIPSIS);
+    let (mut low, mut high) = (0, starts.len() - 1);
+
+    while low < high {
+        let mid = (low + high + 1) / 2;
+
+        if measure(&truncated(mid)) <= width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    truncated(low)
+}
+
+/// Draws a [`TabBadge`](TabBadge) in the upper right corner of the given
+/// bounds of a tab.
+fn draw_badge<B>(
+    renderer: &Renderer<B>,
+    badge: TabBadge,
+    bounds: Rectangle,
+    style: &Style,
+    text_font: Font,
+) -> Primitive
+where
+    B: Backend + backend::Text,
+{
+    match badge {
+        TabBadge::Dot => Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x + bounds.width - BADGE_DOT_SIZE - BADGE_MARGIN,
+                y: bounds.y + BADGE_MARGIN,
+                width: BADGE_DOT_SIZE,
+                height: BADGE_DOT_SIZE,
+            },
+            background: style.badge_background,
+            border_radius: (BADGE_DOT_SIZE / 2.0) as u16,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        },
+        TabBadge::Count(count) => {
+            let content = count.to_string();
+            let (text_width, _) = renderer.backend().measure(
+                &content,
+                BADGE_TEXT_SIZE,
+                text_font,
+                Size::INFINITY,
+            );
+
+            let height = BADGE_TEXT_SIZE + 2.0 * BADGE_PADDING;
+            let width = (text_width + 2.0 * BADGE_PADDING).max(height);
+            let badge_bounds = Rectangle {
+                x: bounds.x + bounds.width - width - BADGE_MARGIN,
+                y: bounds.y + BADGE_MARGIN,
+                width,
+                height,
+            };
 
             Primitive::Group {
                 primitives: vec![
-                    background,
-
-                    Primitive::Text {
-                        content: icon.to_string(),
-                        font: icon_font,
-                        size: icon_bounds.height,
-                        bounds: Rectangle {
-                            x: icon_bounds.center_x(),
-                            y: icon_bounds.center_y(),
-                            .. icon_bounds
-                        },
-                        color: style.icon_color,
-                        horizontal_alignment: HorizontalAlignment::Center,
-                        vertical_alignment: VerticalAlignment::Center,
+                    Primitive::Quad {
+                        bounds: badge_bounds,
+                        background: style.badge_background,
+                        border_radius: (height / 2.0) as u16,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
                     },
-
                     Primitive::Text {
-                        content: text.to_string(),
+                        content,
                         font: text_font,
-                        size: text_bounds.height,
+                        size: BADGE_TEXT_SIZE,
                         bounds: Rectangle {
-                            x: text_bounds.center_x(),
-                            y: text_bounds.center_y(),
-                            .. text_bounds   
+                            x: badge_bounds.center_x(),
+                            y: badge_bounds.center_y(),
+                            .. badge_bounds
                         },
-                        color: style.text_color,
+                        color: style.badge_text_color,
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
                     },
-
-                    cross,
                 ]
             }
         },
-    };
+    }
+}
 
-    (
-        primitive,
-        if is_mouse_over {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::default()
-        }
-    )
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Measures a text with characters of the same width.
+    fn measure(text: &str) -> f32 {
+        text.chars().count() as f32 * 10.0
+    }
+
+    #[test]
+    fn truncate_keeps_fitting_texts() {
+        assert_eq!(truncate_with("", 0.0, measure), "");
+        assert_eq!(truncate_with("Tab", 30.0, measure), "Tab");
+        assert_eq!(truncate_with("Tab", 29.5, measure), "Tab");
+    }
+
+    #[test]
+    fn truncate_adds_an_ellipsis() {
+        assert_eq!(truncate_with("Hello world", 60.0, measure), "Hello\u{2026}");
+        assert_eq!(truncate_with("Hello world", 100.0, measure), "Hello wor\u{2026}");
+    }
+
+    #[test]
+    fn truncate_trims_trailing_whitespace() {
+        assert_eq!(truncate_with("Hello world", 70.0, measure), "Hello\u{2026}");
+    }
+
+    #[test]
+    fn truncate_keeps_whole_characters() {
+        assert_eq!(truncate_with("\u{e4}\u{f6}\u{fc}\u{df}", 30.0, measure), "\u{e4}\u{f6}\u{2026}");
+        assert_eq!(truncate_with("Tab", 0.0, measure), "\u{2026}");
+    }
+}
@@ -10,7 +10,9 @@ use iced_graphics::{Backend, Point, Primitive, Rectangle, Renderer, backend};
 use iced_native::{Layout, mouse};
 pub use tabs::tab_bar_position::TabBarPosition;
 use crate::native::tabs;
-pub use crate::style::tab_bar::{Style, StyleSheet};
+pub use crate::style::tab_bar::{
+    DropZoneStyle, IndicatorPosition, MenuStyle, Style, StyleSheet
+};
 
 /// A [`Tabs`](Tabs) widget for showing a [`TabBar`](super::tab_bar::TabBar)
 /// along with the tab's content.
@@ -27,13 +29,15 @@ where
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
-        active_tab: usize,
+        _active_tab: usize,
         //tab_bar: &crate::native::TabBar<Message, Self>,
         tab_bar: Self::Output,
-        tabs: &[iced_native::Element<'_, Message, Self>],
+        content: Option<&iced_native::Element<'_, Message, Self>>,
         layout: Layout<'_>,
         cursor_position: Point,
         tab_bar_position: &TabBarPosition,
+        drop_zone: Option<Rectangle>,
+        style_sheet: &Self::Style,
         viewport: &Rectangle,
     ) -> Self::Output {
         //let bounds = layout.bounds();
@@ -51,8 +55,8 @@ where
             }
         };*/
         let tab_content_layout = match tab_bar_position {
-            TabBarPosition::Top => children.last().unwrap(),
-            TabBarPosition::Bottom => children.next().unwrap()
+            TabBarPosition::Top | TabBarPosition::Left => children.last().unwrap(),
+            TabBarPosition::Bottom | TabBarPosition::Right => children.next().unwrap()
         };
 
         let mut primitives = Vec::new();
@@ -66,7 +70,7 @@ where
 
         primitives.push(tab_bar_primitive);
 
-        if let Some(element) = tabs.get(active_tab) {
+        if let Some(element) = content {
             let (tab_content_primitive, new_mouse_interaction) =
                 element.draw(self, defaults, tab_content_layout, cursor_position, viewport);
 
@@ -77,6 +81,20 @@ where
             primitives.push(tab_content_primitive);
         }
 
+        if let Some(drop_zone) = drop_zone {
+            let style = style_sheet.drop_zone();
+
+            primitives.push(Primitive::Quad {
+                bounds: drop_zone,
+                background: style.background,
+                border_radius: 0,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+
+            mouse_interaction = mouse::Interaction::Grabbing;
+        }
+
         (
             Primitive::Group {
                 primitives: primitives,
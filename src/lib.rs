@@ -34,6 +34,10 @@ mod platform {
         crate::graphics::card,
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "dialog")]
+    pub use crate::graphics::dialog;
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_button")]
     pub use {
@@ -52,7 +56,7 @@ mod platform {
     #[cfg(feature = "tab_bar")]
     pub use {
         tab_bar::{
-            TabBar, TabLabel,
+            TabBadge, TabBar, TabLabel,
         },
         crate::graphics::tab_bar,
     };
@@ -83,6 +87,10 @@ mod platform {
         card, card::Card,
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "dialog")]
+    pub use crate::web::dialog;
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_button")]
     pub use crate::web::{
@@ -94,6 +102,18 @@ mod platform {
     pub use crate::web::{
         modal, modal::Modal,
     };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tab_bar")]
+    pub use crate::web::{
+        tab_bar, tab_bar::{TabBadge, TabBar, TabLabel},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tabs")]
+    pub use crate::web::{
+        tabs, tabs::{Tabs, TabBarPosition},
+    };
 }
 
 #[doc(no_inline)]
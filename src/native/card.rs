@@ -6,6 +6,8 @@ use std::hash::Hash;
 use iced_native::{Align, Rectangle, mouse};
 use iced_native::{Clipboard, Element, Event, Layout, Length, Point, Size, Widget, event};
 
+use crate::native::SCROLL_LINE_HEIGHT;
+
 /// A card consisting of a head, body and optional foot.
 /// 
 /// # Example
@@ -37,9 +39,20 @@ pub struct Card<'a, Message, Renderer: self::Renderer> {
     padding_foot: f32,
     close_size: Option<f32>,
     on_close: Option<Message>,
+    on_toggle: Option<Message>,
+    expanded: bool,
+    head_actions: Vec<(char, Message)>,
+    on_press: Option<(&'a mut State, Message)>,
+    selected: bool,
+    scroll_state: Option<&'a mut ScrollState>,
+    scrollbar_width: u16,
+    media_position: MediaPosition,
+    media_fit: MediaFit,
+    media_height: Option<u32>,
     head: Element<'a, Message, Renderer>,
     body: Element<'a, Message, Renderer>,
     foot: Option<Element<'a, Message, Renderer>>,
+    media: Option<Element<'a, Message, Renderer>>,
     style: <Renderer as self::Renderer>::Style,
 }
 
@@ -63,9 +76,20 @@ where
             padding_foot: <Renderer as self::Renderer>::DEFAULT_PADDING,
             close_size: None,
             on_close: None,
+            on_toggle: None,
+            expanded: true,
+            head_actions: Vec::new(),
+            on_press: None,
+            selected: false,
+            scroll_state: None,
+            scrollbar_width: 10,
+            media_position: MediaPosition::default(),
+            media_fit: MediaFit::default(),
+            media_height: None,
             head: head.into(),
             body: body.into(),
             foot: None,
+            media: None,
             style: <Renderer as self::Renderer>::Style::default(),
         }
     }
@@ -80,6 +104,45 @@ where
         self
     }
 
+    /// Sets the [`Element`](iced_native::Element) of the media of the
+    /// [`Card`](Card), like an image.
+    /// 
+    /// The media spans the width of the [`Card`](Card) and should fill the
+    /// width it gets, for example an image with a width of
+    /// [`Length::Fill`](iced_native::Length::Fill).
+    pub fn media<M>(mut self, media: M) -> Self
+    where
+        M: Into<Element<'a, Message, Renderer>>
+    {
+        self.media = Some(media.into());
+        self
+    }
+
+    /// Sets the [`MediaPosition`](MediaPosition) of the media of the
+    /// [`Card`](Card).
+    pub fn media_position(mut self, position: MediaPosition) -> Self {
+        self.media_position = position;
+        self
+    }
+
+    /// Sets how the media is fitted into the media area of the
+    /// [`Card`](Card).
+    /// 
+    /// This only has an effect if the [`media_height`](Card::media_height)
+    /// is set.
+    pub fn media_fit(mut self, fit: MediaFit) -> Self {
+        self.media_fit = fit;
+        self
+    }
+
+    /// Sets the height of the media area of the [`Card`](Card).
+    /// 
+    /// By default, the media area is as high as the media.
+    pub fn media_height(mut self, height: u32) -> Self {
+        self.media_height = Some(height);
+        self
+    }
+
     /// Sets the width of the [`Card`](Card).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -148,6 +211,77 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the head or the chevron
+    /// icon of the [`Card`](Card) is pressed.
+    /// 
+    /// Setting this enables the drawing of a chevron icon on the
+    /// [`Card`](Card). You have to toggle the [`expanded`](Card::expanded)
+    /// flag by yourself.
+    pub fn on_toggle(mut self, msg: Message) -> Self {
+        self.on_toggle = Some(msg);
+        self
+    }
+
+    /// Adds an action button showing the given icon to the head of the
+    /// [`Card`](Card), producing the given message when it is pressed.
+    /// 
+    /// The actions are placed in the order they were added in front of the
+    /// chevron and the close icon. The icon is drawn with the icon font of
+    /// the renderer, so icons from `graphics::icons` besides the ones used by
+    /// the widgets of this library need the `icons` feature.
+    pub fn head_action(mut self, icon: impl Into<char>, msg: Message) -> Self {
+        self.head_actions.push((icon.into(), msg));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Card`](Card) is
+    /// pressed and released over its area, using the given [`State`](State)
+    /// to track the press.
+    /// 
+    /// Presses captured by the content, the head actions, the chevron or
+    /// the close icon do not press the [`Card`](Card).
+    pub fn on_press(mut self, state: &'a mut State, msg: Message) -> Self {
+        self.on_press = Some((state, msg));
+        self
+    }
+
+    /// Sets whether the [`Card`](Card) is selected.
+    /// 
+    /// A selected [`Card`](Card) is drawn with the
+    /// [`selected`](crate::style::card::StyleSheet::selected) appearance.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Makes the body of the [`Card`](Card) scrollable, using the given
+    /// [`ScrollState`](ScrollState) to keep the scroll position.
+    /// 
+    /// If the height of the [`Card`](Card) is constrained, for example by
+    /// [`max_height`](Card::max_height), the body shrinks to the space left
+    /// by the head and the foot and scrolls its content, while the head and
+    /// the foot stay in place.
+    pub fn scrollable(mut self, state: &'a mut ScrollState) -> Self {
+        self.scroll_state = Some(state);
+        self
+    }
+
+    /// Sets the width of the scrollbar of a scrollable body of the
+    /// [`Card`](Card).
+    pub fn scrollbar_width(mut self, width: u16) -> Self {
+        self.scrollbar_width = width;
+        self
+    }
+
+    /// Sets whether the [`Card`](Card) is expanded.
+    /// 
+    /// A collapsed [`Card`](Card) only shows its head. It is expanded by
+    /// default.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
     /// Sets the style of the [`Card`](Card).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self
     {
@@ -156,6 +290,48 @@ where
     }
 }
 
+impl<'a, Message, Renderer> Card<'a, Message, Renderer>
+where 
+    Renderer: self::Renderer,
+{
+    /// Returns the [`Scrollbar`](Scrollbar) of the body with the given
+    /// [`Layout`](iced_native::Layout) if the body is scrollable and its
+    /// content does not fit into it.
+    fn scrollbar(&self, body_layout: Layout<'_>) -> Option<Scrollbar> {
+        let state = self.scroll_state.as_ref()?;
+        let bounds = body_layout.bounds();
+        let content_height = body_layout.children().next()?.bounds().height
+            + 2.0 * self.padding_body;
+
+        if content_height <= bounds.height {
+            return None;
+        }
+
+        let offset = state.offset(bounds.height, content_height);
+        let width = f32::from(self.scrollbar_width);
+        let ratio = bounds.height / content_height;
+        let x = bounds.x + bounds.width - width;
+
+        Some(Scrollbar {
+            bounds: Rectangle {
+                x,
+                y: bounds.y,
+                width,
+                height: bounds.height,
+            },
+            scroller: Rectangle {
+                x,
+                y: bounds.y + offset * ratio,
+                width,
+                height: (bounds.height * ratio).max(width),
+            },
+            offset,
+            content_height,
+            is_grabbed: state.scroller_grabbed_at.is_some(),
+        })
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Card<'a, Message, Renderer>
 where 
@@ -179,50 +355,99 @@ where
             .max_width(self.max_width)
             .max_height(self.max_height);
 
-        let head_node = head_node(
+        let mut head_node = head_node(
             renderer,
             &limits,
             &self.head,
             self.padding_head,
             self.width,
+            self.head_actions.len(),
+            self.on_toggle.is_some(),
             self.on_close.is_some(),
             self.close_size,
         );
 
-        let mut body_node = body_node(
-            renderer,
-            &limits,
-            &self.body,
-            self.padding_body,
-            self.width,
-        );
+        let mut foot_node = match self.foot.as_ref() {
+            Some(foot) if self.expanded => {
+                foot_node(renderer, &limits, foot, self.padding_foot, self.width)
+            },
+            _ => iced_native::layout::Node::default(),
+        };
 
-        body_node.move_to(Point::new(
-            body_node.bounds().x,
-            body_node.bounds().y + head_node.bounds().height,
-        ));
+        // The media spans the width of the head.
+        let mut media_node = match self.media.as_ref() {
+            Some(media) if self.expanded => media_node(
+                renderer,
+                media,
+                head_node.bounds().width,
+                self.media_height.map(|height| height as f32),
+                self.media_fit,
+            ),
+            _ => iced_native::layout::Node::default(),
+        };
 
-        let mut foot_node = if let Some(foot) = self.foot.as_ref() {
-            foot_node(renderer, &limits, foot, self.padding_foot, self.width)
-        } else {
+        match self.media_position {
+            MediaPosition::AboveHead => head_node.move_to(Point::new(
+                head_node.bounds().x,
+                head_node.bounds().y + media_node.bounds().height,
+            )),
+            MediaPosition::BelowHead => media_node.move_to(Point::new(
+                media_node.bounds().x,
+                media_node.bounds().y + head_node.bounds().height,
+            )),
+        }
+
+        // A collapsed card hides its body and foot.
+        let mut body_node = if !self.expanded {
             iced_native::layout::Node::default()
+        } else if self.scroll_state.is_some() {
+            // The scrollable body gets the height left by the head and foot.
+            scrollable_body_node(
+                renderer,
+                &limits,
+                &self.body,
+                self.padding_body,
+                self.width,
+                (limits.max().height
+                    - head_node.bounds().height
+                    - media_node.bounds().height
+                    - foot_node.bounds().height
+                ).max(0.0),
+            )
+        } else {
+            body_node(
+                renderer,
+                &limits,
+                &self.body,
+                self.padding_body,
+                self.width,
+            )
         };
 
+        body_node.move_to(Point::new(
+            body_node.bounds().x,
+            body_node.bounds().y
+                + head_node.bounds().height
+                + media_node.bounds().height,
+        ));
+
         foot_node.move_to(Point::new(
             foot_node.bounds().x,
             foot_node.bounds().y
                 + head_node.bounds().height
+                + media_node.bounds().height
                 + body_node.bounds().height
         ));
 
         iced_native::layout::Node::with_children(
             Size::new(
-                body_node.size().width,
+                head_node.size().width.max(body_node.size().width),
                 head_node.size().height
+                    + media_node.size().height
                     + body_node.size().height
                     + foot_node.size().height
             ),
-            vec!(head_node, body_node, foot_node),
+            vec!(head_node, body_node, foot_node, media_node),
         )
     }
 
@@ -248,6 +473,23 @@ where
             clipboard,
         );
 
+        let mut actions_status = event::Status::Ignored;
+        for ((_, msg), action_layout) in self.head_actions.iter()
+            .zip(head_children.by_ref())
+        {
+            if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+                if action_layout.bounds().contains(cursor_position) {
+                    messages.push(msg.clone());
+                    actions_status = event::Status::Captured;
+                }
+            }
+        }
+
+        // The chevron icon is part of the head.
+        if self.on_toggle.is_some() {
+            let _ = head_children.next();
+        }
+
         let close_status = head_children.next().map_or(
             event::Status::Ignored,
             |close_layout| {
@@ -270,34 +512,157 @@ where
             }
         );
 
-        let body_layout = children.next().unwrap();
-        let mut body_children = body_layout.children();
-        let body_status = self.body.on_event(
-            event.clone(),
-            body_children.next().unwrap(),
-            cursor_position,
-            messages,
-            renderer,
-            clipboard
-        );
+        // The head toggles the card unless its content or the close icon
+        // captured the press.
+        let toggle_status = match (&self.on_toggle, &event) {
+            (
+                Some(on_toggle),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ) if head_status == event::Status::Ignored
+                && actions_status == event::Status::Ignored
+                && close_status == event::Status::Ignored
+                && head_layout.bounds().contains(cursor_position) =>
+            {
+                messages.push(on_toggle.clone());
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        };
+
+        let head_status = head_status
+            .merge(actions_status)
+            .merge(close_status)
+            .merge(toggle_status);
+
+        // A collapsed card only passes the events to its head.
+        let status = if self.expanded {
+            let body_layout = children.next().unwrap();
+            let scrollbar = self.scrollbar(body_layout);
+            let mut body_children = body_layout.children();
+            let content_layout = body_children.next().unwrap();
+
+            let body_status = match (self.scroll_state.as_mut(), scrollbar) {
+                (Some(state), Some(scrollbar)) => {
+                    let scrollbar_status = scrollbar_event(
+                        state,
+                        &event,
+                        &scrollbar,
+                        cursor_position,
+                    );
+
+                    if scrollbar_status == event::Status::Captured {
+                        scrollbar_status
+                    } else {
+                        let body_bounds = body_layout.bounds();
+                        let is_mouse_over_body = body_bounds.contains(cursor_position)
+                            && !scrollbar.bounds.contains(cursor_position);
+
+                        let content_status = self.body.on_event(
+                            event.clone(),
+                            content_layout,
+                            if is_mouse_over_body {
+                                Point::new(
+                                    cursor_position.x,
+                                    cursor_position.y + scrollbar.offset
+                                )
+                            } else {
+                                Point::new(-1.0, -1.0)
+                            },
+                            messages,
+                            renderer,
+                            clipboard
+                        );
+
+                        match event {
+                            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                                if content_status == event::Status::Ignored
+                                    && is_mouse_over_body =>
+                            {
+                                let delta_y = match delta {
+                                    mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_LINE_HEIGHT,
+                                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                                };
+
+                                state.scroll(
+                                    delta_y,
+                                    body_bounds.height,
+                                    scrollbar.content_height
+                                );
+                                event::Status::Captured
+                            },
+                            _ => content_status,
+                        }
+                    }
+                },
+                _ => self.body.on_event(
+                    event.clone(),
+                    content_layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard
+                ),
+            };
+
+            let foot_layout = children.next().unwrap();
+            let mut foot_children = foot_layout.children();
+            let foot_status = self.foot.as_mut().map(
+                |foot| foot.on_event(
+                    event.clone(),
+                    foot_children.next().unwrap(),
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard
+                )
+            ).unwrap_or(event::Status::Ignored);
+
+            head_status
+                .merge(body_status)
+                .merge(foot_status)
+        } else {
+            head_status
+        };
 
-        let foot_layout = children.next().unwrap();
-        let mut foot_children = foot_layout.children();
-        let foot_status = self.foot.as_mut().map(
-            |foot| foot.on_event(
-                event,
-                foot_children.next().unwrap(),
+        let media_status = match (self.media.as_mut(), layout.children().nth(3)) {
+            (Some(media), Some(media_layout)) if self.expanded => media.on_event(
+                event.clone(),
+                media_layout.children().next().unwrap(),
                 cursor_position,
                 messages,
                 renderer,
                 clipboard
-            )
-        ).unwrap_or(event::Status::Ignored);
+            ),
+            _ => event::Status::Ignored,
+        };
+        let status = status.merge(media_status);
 
-        head_status
-            .merge(close_status)
-            .merge(body_status)
-            .merge(foot_status)
+        let press_status = match (self.on_press.as_mut(), event) {
+            (
+                Some((state, _)),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            ) if status == event::Status::Ignored
+                && layout.bounds().contains(cursor_position) =>
+            {
+                state.is_pressed = true;
+                event::Status::Captured
+            },
+            (
+                Some((state, on_press)),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            ) if state.is_pressed => {
+                state.is_pressed = false;
+
+                if layout.bounds().contains(cursor_position) {
+                    messages.push(on_press.clone());
+                }
+
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        };
+
+        status.merge(press_status)
     }
 
     fn draw(
@@ -315,6 +680,16 @@ where
             &self.head,
             &self.body,
             &self.foot,
+            &self.media,
+            &self.head_actions.iter().map(|(icon, _)| *icon).collect::<Vec<char>>(),
+            self.on_toggle.as_ref().map(|_| self.expanded),
+            self.on_press.as_ref().map(|(state, _)| state.is_pressed),
+            self.selected,
+            if self.expanded {
+                layout.children().nth(1).and_then(|body_layout| self.scrollbar(body_layout))
+            } else {
+                None
+            },
             &self.style,
             viewport
         )
@@ -328,9 +703,17 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.head_actions.len().hash(state);
+        self.on_toggle.is_some().hash(state);
+        self.expanded.hash(state);
+        self.scroll_state.is_some().hash(state);
+        self.media_position.hash(state);
+        self.media_fit.hash(state);
+        self.media_height.hash(state);
         self.head.hash_layout(state);
         self.body.hash_layout(state);
         if let Some(foot) = self.foot.as_ref() { foot.hash_layout(state) };
+        if let Some(media) = self.media.as_ref() { media.hash_layout(state) };
     }
 }
 
@@ -340,6 +723,8 @@ fn head_node<'a, Message, Renderer>(
     head: &Element<'a, Message, Renderer>,
     padding: f32,
     width: Length,
+    action_count: usize,
+    on_toggle: bool,
     on_close: bool,
     close_size: Option<f32>,
 ) -> iced_native::layout::Node
@@ -353,6 +738,16 @@ where
         .pad(padding);
 
     let close_size = close_size.unwrap_or_else(||renderer.default_size());
+    let mut actions: Vec<iced_native::layout::Node> = (0..action_count)
+        .map(|_| iced_native::layout::Node::new(Size::new(close_size, close_size)))
+        .collect();
+    limits = limits.shrink(Size::new(action_count as f32 * close_size, 0.0));
+    let mut toggle = if on_toggle {
+        limits = limits.shrink(Size::new(close_size, 0.0));
+        Some(iced_native::layout::Node::new(Size::new(close_size, close_size)))
+    } else {
+        None
+    };
     let mut close = if on_close {
         limits = limits.shrink(Size::new(close_size, 0.0));
         Some(iced_native::layout::Node::new(Size::new(close_size, close_size)))
@@ -366,7 +761,8 @@ where
     head.move_to(Point::new(padding, padding));
     head.align(Align::Start, Align::Center, head.size());
 
-    if let Some(node) = close.as_mut() {
+    // The actions and the chevron icon are placed in front of the close icon.
+    for node in actions.iter_mut().chain(toggle.iter_mut()).chain(close.iter_mut()) {
         size = Size::new(
             size.width + close_size,
             size.height
@@ -378,10 +774,11 @@ where
 
     iced_native::layout::Node::with_children(
         size.pad(padding),
-        match close {
-            Some(node) => vec![head, node],
-            None => vec![head],
-        },
+        std::iter::once(head)
+            .chain(actions)
+            .chain(toggle)
+            .chain(close)
+            .collect(),
     )
 }
 
@@ -410,6 +807,139 @@ where
     iced_native::layout::Node::with_children(size.pad(padding), vec![body])
 }
 
+fn scrollable_body_node<'a, Message, Renderer>(
+    renderer: &Renderer,
+    limits: &iced_native::layout::Limits,
+    body: &Element<'a, Message, Renderer>,
+    padding: f32,
+    width: Length,
+    max_height: f32,
+) -> iced_native::layout::Node
+where
+    Renderer: self::Renderer,
+{
+    // The content may be as high as it wants to be.
+    let limits = iced_native::layout::Limits::new(
+        Size::ZERO,
+        Size::new(limits.max().width, f32::INFINITY),
+    )
+    .width(width)
+    .pad(padding);
+
+    let mut body = body.layout(renderer, &limits);
+    let size = limits.resolve(body.size()).pad(padding);
+
+    body.move_to(Point::new(padding, padding));
+
+    iced_native::layout::Node::with_children(
+        Size::new(size.width, size.height.min(max_height)),
+        vec![body],
+    )
+}
+
+/// Handles the given event for the scrollbar of a scrollable body of a
+/// [`Card`](Card).
+fn scrollbar_event(
+    state: &mut ScrollState,
+    event: &Event,
+    scrollbar: &Scrollbar,
+    cursor_position: Point,
+) -> event::Status {
+    let height = scrollbar.bounds.height;
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            if scrollbar.bounds.contains(cursor_position) =>
+        {
+            if scrollbar.scroller.contains(cursor_position) {
+                state.scroller_grabbed_at = Some(
+                    (cursor_position.y - scrollbar.scroller.y)
+                        / scrollbar.scroller.height
+                );
+            } else {
+                // Jump to the clicked position and grab the scroller there.
+                state.scroller_grabbed_at = Some(0.5);
+                state.scroll_to(
+                    scrollbar.scroll_percentage(cursor_position, 0.5),
+                    height,
+                    scrollbar.content_height,
+                );
+            }
+
+            event::Status::Captured
+        },
+        Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+            match state.scroller_grabbed_at {
+                Some(grabbed_at) => {
+                    state.scroll_to(
+                        scrollbar.scroll_percentage(cursor_position, grabbed_at),
+                        height,
+                        scrollbar.content_height,
+                    );
+
+                    event::Status::Captured
+                },
+                None => event::Status::Ignored,
+            }
+        },
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            if state.scroller_grabbed_at.is_some() =>
+        {
+            state.scroller_grabbed_at = None;
+            event::Status::Captured
+        },
+        _ => event::Status::Ignored,
+    }
+}
+
+fn media_node<'a, Message, Renderer>(
+    renderer: &Renderer,
+    media: &Element<'a, Message, Renderer>,
+    width: f32,
+    height: Option<f32>,
+    fit: MediaFit,
+) -> iced_native::layout::Node
+where
+    Renderer: self::Renderer,
+{
+    let mut content = media.layout(
+        renderer,
+        &iced_native::layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+    );
+    let content_height = content.size().height;
+    let height = height.unwrap_or(content_height);
+
+    match fit {
+        // Scale the media up until it covers the whole media area.
+        MediaFit::Cover if content_height > 0.0 && content_height < height => {
+            content = media.layout(
+                renderer,
+                &iced_native::layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(width * height / content_height, height),
+                ),
+            );
+        },
+        // Scale the media down until it fits into the media area.
+        MediaFit::Contain if content_height > height => {
+            content = media.layout(
+                renderer,
+                &iced_native::layout::Limits::new(Size::ZERO, Size::new(width, height)),
+            );
+        },
+        _ => {},
+    }
+
+    // The media is centered, so a covering media gets cut at both sides.
+    let size = content.size();
+    content.move_to(Point::new(
+        (width - size.width) / 2.0,
+        (height - size.height) / 2.0,
+    ));
+
+    iced_native::layout::Node::with_children(Size::new(width, height), vec![content])
+}
+
 fn foot_node<'a, Message, Renderer>(
     renderer: &Renderer,
     limits: &iced_native::layout::Limits,
@@ -435,6 +965,139 @@ where
     iced_native::layout::Node::with_children(size.pad(padding), vec![foot])
 }
 
+/// The position of the media of a [`Card`](Card).
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum MediaPosition {
+    /// The media is placed above the head.
+    AboveHead,
+
+    /// The media is placed between the head and the body.
+    BelowHead,
+}
+
+impl Default for MediaPosition {
+    fn default() -> Self {
+        MediaPosition::AboveHead
+    }
+}
+
+/// How the media of a [`Card`](Card) is fitted into its media area.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum MediaFit {
+    /// The media is scaled to cover the whole media area and the parts
+    /// sticking out are cut off.
+    Cover,
+
+    /// The media is scaled to fit into the media area, leaving the
+    /// [`media_background`](crate::style::card::Style::media_background)
+    /// visible around it.
+    Contain,
+}
+
+impl Default for MediaFit {
+    fn default() -> Self {
+        MediaFit::Cover
+    }
+}
+
+/// The state of a clickable [`Card`](Card).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    is_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`](State) of a [`Card`](Card).
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`Card`](Card) is currently pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+}
+
+/// The state of the scrollable body of a [`Card`](Card).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScrollState {
+    offset: f32,
+    scroller_grabbed_at: Option<f32>,
+}
+
+impl ScrollState {
+    /// Creates a new [`ScrollState`](ScrollState) of a [`Card`](Card).
+    pub fn new() -> Self {
+        ScrollState::default()
+    }
+
+    /// Returns the current scroll offset of a body with the given height
+    /// showing content of the given height.
+    pub fn offset(&self, height: f32, content_height: f32) -> f32 {
+        self.offset.min((content_height - height).max(0.0)).max(0.0)
+    }
+
+    /// Scrolls a body with the given height showing content of the given
+    /// height by the given amount of pixels.
+    pub fn scroll(&mut self, delta_y: f32, height: f32, content_height: f32) {
+        if content_height <= height {
+            return;
+        }
+
+        self.offset = (self.offset - delta_y)
+            .max(0.0)
+            .min(content_height - height);
+    }
+
+    /// Scrolls a body with the given height showing content of the given
+    /// height to the given percentage between `0.0` and `1.0`.
+    pub fn scroll_to(&mut self, percentage: f32, height: f32, content_height: f32) {
+        self.offset = ((content_height - height) * percentage.max(0.0).min(1.0))
+            .max(0.0);
+    }
+
+    /// Scrolls the body back to its top.
+    pub fn reset(&mut self) {
+        self.offset = 0.0;
+    }
+
+    /// Returns whether the scroller is currently grabbed.
+    pub fn is_scroller_grabbed(&self) -> bool {
+        self.scroller_grabbed_at.is_some()
+    }
+}
+
+/// The scrollbar of a scrollable body of a [`Card`](Card) whose content does
+/// not fit into it.
+#[derive(Clone, Copy, Debug)]
+pub struct Scrollbar {
+    /// The bounds of the scrollbar.
+    pub bounds: Rectangle,
+
+    /// The bounds of the scroller.
+    pub scroller: Rectangle,
+
+    /// The current scroll offset of the body.
+    pub offset: f32,
+
+    /// The height of the content of the body including its padding.
+    pub content_height: f32,
+
+    /// Whether the scroller is currently grabbed.
+    pub is_grabbed: bool,
+}
+
+impl Scrollbar {
+    /// Returns the percentage to scroll to if the scroller is grabbed at the
+    /// given relative position and moved to the given cursor position.
+    fn scroll_percentage(&self, cursor_position: Point, grabbed_at: f32) -> f32 {
+        (cursor_position.y
+            - self.bounds.y
+            - self.scroller.height * grabbed_at)
+            / (self.bounds.height - self.scroller.height)
+    }
+}
+
 /// The renderer of a [`Card`](Card).
 /// 
 /// Your renderer will need to implement this trait before being
@@ -451,6 +1114,11 @@ pub trait Renderer: iced_native::Renderer {
     fn default_size(&self) -> f32;
 
     /// Draws a [`Card`](Card).
+    /// 
+    /// `pressed` is `None` if the [`Card`](Card) is not clickable, otherwise
+    /// it tells whether the [`Card`](Card) is currently pressed.
+    /// `scrollbar` is only given if the body is scrollable and its content
+    /// does not fit into it.
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -459,6 +1127,12 @@ pub trait Renderer: iced_native::Renderer {
         head: &Element<'_, Message, Self>,
         body: &Element<'_, Message, Self>,
         foot: &Option<Element<'_, Message, Self>>,
+        media: &Option<Element<'_, Message, Self>>,
+        head_actions: &[char],
+        expanded: Option<bool>,
+        pressed: Option<bool>,
+        selected: bool,
+        scrollbar: Option<Scrollbar>,
         style_sheet: &Self::Style,
         viewport: &Rectangle,
     ) -> Self::Output;
@@ -482,6 +1156,12 @@ impl Renderer for iced_native::renderer::Null {
         _head: &Element<'_, Message, Self>,
         _body: &Element<'_, Message, Self>,
         _foot: &Option<Element<'_, Message, Self>>,
+        _media: &Option<Element<'_, Message, Self>>,
+        _head_actions: &[char],
+        _expanded: Option<bool>,
+        _pressed: Option<bool>,
+        _selected: bool,
+        _scrollbar: Option<Scrollbar>,
         _style_sheet: &Self::Style,
         _viewport: &Rectangle,
     ) -> Self::Output {}
@@ -0,0 +1,849 @@
+//! Ready-made dialogs showing a [`Card`](crate::native::card::Card) in a
+//! [`Modal`](crate::native::modal::Modal).
+//! 
+//! *This API requires the following crate features to be activated: dialog*
+use std::rc::Rc;
+
+use iced_native::{
+    Button, Column, Element, HorizontalAlignment, Length, Row, Text, TextInput,
+    button, column, container, row, text, text_input,
+};
+
+use crate::native::{Card, Modal, card, modal};
+use crate::style::{button::{Danger, Primary, Secondary}, colors};
+
+/// The default maximum width of the card of a dialog.
+const DEFAULT_MAX_WIDTH: u32 = 300;
+
+/// A function creating the style of the [`Card`](Card) of a dialog.
+type CardStyle<'a, Renderer> =
+    Box<dyn Fn() -> <Renderer as card::Renderer>::Style + 'a>;
+
+/// An alert dialog showing a message that has to be acknowledged with an OK
+/// button.
+/// 
+/// The OK message is also produced when the dialog is closed by its close
+/// button, the Escape key or a click on the backdrop.
+/// 
+/// # Example
+/// ```
+/// # use iced_aw::native::{dialog, modal};
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type Alert<'a, Message> = dialog::Alert<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseAlert,
+/// }
+/// 
+/// let mut state = modal::State::new(dialog::State::new());
+/// 
+/// let alert = Alert::new(
+///     &mut state,
+///     Text::new("Underlay"),
+///     "Saved",
+///     "Your changes have been saved.",
+///     Message::CloseAlert,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Alert<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer + card::Renderer,
+{
+    state: &'a mut modal::State<State>,
+    underlay: Element<'a, Message, Renderer>,
+    title: String,
+    message: String,
+    on_ok: Message,
+    ok_label: String,
+    max_width: u32,
+    placement: modal::Placement,
+    animation: Option<modal::Animation>,
+    restore_focus: bool,
+    style: <Renderer as modal::Renderer>::Style,
+    card_style: CardStyle<'a, Renderer>,
+}
+
+impl<'a, Message, Renderer> Alert<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer + card::Renderer,
+{
+    /// Creates a new [`Alert`](Alert) wrapping the underlying element to
+    /// show the given title and message on top of it.
+    /// 
+    /// `on_ok` is produced when the [`Alert`](Alert) is acknowledged.
+    pub fn new<U, T, M>(
+        state: &'a mut modal::State<State>,
+        underlay: U,
+        title: T,
+        message: M,
+        on_ok: Message,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        T: Into<String>,
+        M: Into<String>,
+    {
+        Alert {
+            state,
+            underlay: underlay.into(),
+            title: title.into(),
+            message: message.into(),
+            on_ok,
+            ok_label: String::from("Ok"),
+            max_width: DEFAULT_MAX_WIDTH,
+            placement: modal::Placement::default(),
+            animation: None,
+            restore_focus: true,
+            style: Default::default(),
+            card_style: Box::new(<<Renderer as card::Renderer>::Style as Default>::default),
+        }
+    }
+
+    /// Sets the label of the OK button of the [`Alert`](Alert).
+    pub fn ok_label(mut self, label: impl Into<String>) -> Self {
+        self.ok_label = label.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`Alert`](Alert).
+    pub fn max_width(mut self, width: u32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Sets the [`Placement`](modal::Placement) of the [`Alert`](Alert).
+    /// 
+    /// The [`Alert`](Alert) is centered by default.
+    pub fn placement(mut self, placement: modal::Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Animation`](modal::Animation) of opening and closing the
+    /// [`Alert`](Alert).
+    /// 
+    /// The [`Alert`](Alert) appears and disappears instantly by default.
+    pub fn animation(mut self, animation: modal::Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets whether the focus of the underlay is restored when the
+    /// [`Alert`](Alert) is closed.
+    /// 
+    /// The focused widgets of the underlay keep their focus while the
+    /// [`Alert`](Alert) is shown by default.
+    pub fn restore_focus(mut self, restore: bool) -> Self {
+        self.restore_focus = restore;
+        self
+    }
+
+    /// Sets the style of the [`Modal`](Modal) showing the [`Alert`](Alert).
+    pub fn style(mut self, style: impl Into<<Renderer as modal::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the [`Card`](Card) of the [`Alert`](Alert).
+    pub fn card_style<S>(mut self, style: S) -> Self
+    where
+        S: 'a + Clone + Into<<Renderer as card::Renderer>::Style>,
+    {
+        self.card_style = Box::new(move || style.clone().into());
+        self
+    }
+}
+
+/// A confirm dialog asking to either accept or cancel an action with an OK
+/// and a Cancel button.
+/// 
+/// The cancel message is also produced when the dialog is closed by its
+/// close button, the Escape key or a click on the backdrop. Destructive
+/// actions can be highlighted by styling the OK button as
+/// [`danger`](Confirm::danger).
+/// 
+/// # Example
+/// ```
+/// # use iced_aw::native::{dialog, modal};
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type Confirm<'a, Message> = dialog::Confirm<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Delete,
+///     Cancel,
+/// }
+/// 
+/// let mut state = modal::State::new(dialog::State::new());
+/// 
+/// let confirm = Confirm::new(
+///     &mut state,
+///     Text::new("Underlay"),
+///     "Delete file",
+///     "Do you really want to delete this file?",
+///     Message::Delete,
+///     Message::Cancel,
+/// )
+/// .danger();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Confirm<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer + card::Renderer,
+{
+    state: &'a mut modal::State<State>,
+    underlay: Element<'a, Message, Renderer>,
+    title: String,
+    message: String,
+    on_ok: Message,
+    on_cancel: Message,
+    ok_label: String,
+    cancel_label: String,
+    is_danger: bool,
+    max_width: u32,
+    placement: modal::Placement,
+    animation: Option<modal::Animation>,
+    restore_focus: bool,
+    style: <Renderer as modal::Renderer>::Style,
+    card_style: CardStyle<'a, Renderer>,
+}
+
+impl<'a, Message, Renderer> Confirm<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer + card::Renderer,
+{
+    /// Creates a new [`Confirm`](Confirm) wrapping the underlying element to
+    /// show the given title and message on top of it.
+    /// 
+    /// `on_ok` is produced when the action is accepted and `on_cancel` when
+    /// it is canceled.
+    pub fn new<U, T, M>(
+        state: &'a mut modal::State<State>,
+        underlay: U,
+        title: T,
+        message: M,
+        on_ok: Message,
+        on_cancel: Message,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        T: Into<String>,
+        M: Into<String>,
+    {
+        Confirm {
+            state,
+            underlay: underlay.into(),
+            title: title.into(),
+            message: message.into(),
+            on_ok,
+            on_cancel,
+            ok_label: String::from("Ok"),
+            cancel_label: String::from("Cancel"),
+            is_danger: false,
+            max_width: DEFAULT_MAX_WIDTH,
+            placement: modal::Placement::default(),
+            animation: None,
+            restore_focus: true,
+            style: Default::default(),
+            card_style: Box::new(<<Renderer as card::Renderer>::Style as Default>::default),
+        }
+    }
+
+    /// Sets the label of the OK button of the [`Confirm`](Confirm).
+    pub fn ok_label(mut self, label: impl Into<String>) -> Self {
+        self.ok_label = label.into();
+        self
+    }
+
+    /// Sets the label of the Cancel button of the [`Confirm`](Confirm).
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Styles the OK button of the [`Confirm`](Confirm) with the
+    /// [`danger`](colors::DANGER) color to warn about a destructive action.
+    pub fn danger(mut self) -> Self {
+        self.is_danger = true;
+        self
+    }
+
+    /// Sets the maximum width of the [`Confirm`](Confirm).
+    pub fn max_width(mut self, width: u32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Sets the [`Placement`](modal::Placement) of the [`Confirm`](Confirm).
+    /// 
+    /// The [`Confirm`](Confirm) is centered by default.
+    pub fn placement(mut self, placement: modal::Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Animation`](modal::Animation) of opening and closing the
+    /// [`Confirm`](Confirm).
+    /// 
+    /// The [`Confirm`](Confirm) appears and disappears instantly by default.
+    pub fn animation(mut self, animation: modal::Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets whether the focus of the underlay is restored when the
+    /// [`Confirm`](Confirm) is closed.
+    /// 
+    /// The focused widgets of the underlay keep their focus while the
+    /// [`Confirm`](Confirm) is shown by default.
+    pub fn restore_focus(mut self, restore: bool) -> Self {
+        self.restore_focus = restore;
+        self
+    }
+
+    /// Sets the style of the [`Modal`](Modal) showing the [`Confirm`](Confirm).
+    pub fn style(mut self, style: impl Into<<Renderer as modal::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the [`Card`](Card) of the [`Confirm`](Confirm).
+    pub fn card_style<S>(mut self, style: S) -> Self
+    where
+        S: 'a + Clone + Into<<Renderer as card::Renderer>::Style>,
+    {
+        self.card_style = Box::new(move || style.clone().into());
+        self
+    }
+}
+
+/// A prompt dialog asking for a text with a text input, an OK and a Cancel
+/// button.
+/// 
+/// The text input is focused when the dialog is opened. The entered text is
+/// checked by the optional [`validate`](Prompt::validate) function. As long
+/// as it is invalid, the text cannot be submitted. The error is shown below
+/// the text input, unless the text is still the
+/// [`initial_value`](Prompt::initial_value).
+/// 
+/// The cancel message is also produced when the dialog is closed by its
+/// close button, the Escape key or a click on the backdrop.
+/// 
+/// # Example
+/// ```
+/// # use iced_aw::native::{dialog, modal};
+/// # use iced_native::{Text, renderer::Null};
+/// #
+/// # pub type Prompt<'a, Message> = dialog::Prompt<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NameChanged(String),
+///     Rename(String),
+///     Cancel,
+/// }
+/// 
+/// let mut state = modal::State::new(dialog::State::new());
+/// 
+/// let prompt = Prompt::new(
+///     &mut state,
+///     Text::new("Underlay"),
+///     "Rename file",
+///     "notes.txt",
+///     Message::NameChanged,
+///     Message::Rename,
+///     Message::Cancel,
+/// )
+/// .initial_value("notes.txt")
+/// .validate(|name| if name.is_empty() {
+///     Err(String::from("The name must not be empty."))
+/// } else {
+///     Ok(())
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Prompt<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer + card::Renderer,
+{
+    state: &'a mut modal::State<State>,
+    underlay: Element<'a, Message, Renderer>,
+    title: String,
+    message: Option<String>,
+    placeholder: String,
+    value: String,
+    on_input: Rc<dyn Fn(String) -> Message>,
+    on_submit: Box<dyn Fn(String) -> Message>,
+    on_cancel: Message,
+    validate: Option<Box<dyn Fn(&str) -> Result<(), String>>>,
+    initial_value: Option<String>,
+    ok_label: String,
+    cancel_label: String,
+    max_width: u32,
+    placement: modal::Placement,
+    animation: Option<modal::Animation>,
+    restore_focus: bool,
+    style: <Renderer as modal::Renderer>::Style,
+    card_style: CardStyle<'a, Renderer>,
+}
+
+impl<'a, Message, Renderer> Prompt<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: modal::Renderer + card::Renderer,
+{
+    /// Creates a new [`Prompt`](Prompt) wrapping the underlying element to
+    /// ask for a text on top of it.
+    /// 
+    /// `value` is the current text of the text input and `on_input` is
+    /// called with the new text when it is changed. `on_submit` is called
+    /// with the text when it is submitted by the OK button or the Enter key
+    /// and `on_cancel` is produced when the [`Prompt`](Prompt) is canceled.
+    pub fn new<U, T, F, G>(
+        state: &'a mut modal::State<State>,
+        underlay: U,
+        title: T,
+        value: &str,
+        on_input: F,
+        on_submit: G,
+        on_cancel: Message,
+    ) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+        T: Into<String>,
+        F: 'static + Fn(String) -> Message,
+        G: 'static + Fn(String) -> Message,
+    {
+        Prompt {
+            state,
+            underlay: underlay.into(),
+            title: title.into(),
+            message: None,
+            placeholder: String::new(),
+            value: value.to_owned(),
+            on_input: Rc::new(on_input),
+            on_submit: Box::new(on_submit),
+            on_cancel,
+            validate: None,
+            initial_value: None,
+            ok_label: String::from("Ok"),
+            cancel_label: String::from("Cancel"),
+            max_width: DEFAULT_MAX_WIDTH,
+            placement: modal::Placement::default(),
+            animation: None,
+            restore_focus: true,
+            style: Default::default(),
+            card_style: Box::new(<<Renderer as card::Renderer>::Style as Default>::default),
+        }
+    }
+
+    /// Sets the message shown above the text input of the
+    /// [`Prompt`](Prompt).
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Sets the placeholder of the text input of the [`Prompt`](Prompt).
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the function validating the text of the [`Prompt`](Prompt).
+    /// 
+    /// It returns the error to show if the text is invalid.
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: 'static + Fn(&str) -> Result<(), String>,
+    {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Sets the initial text of the [`Prompt`](Prompt).
+    /// 
+    /// The error of the [`validate`](Prompt::validate) function is only
+    /// shown once the text differs from the initial text, so an empty
+    /// [`Prompt`](Prompt) doesn't start with an error. The text still cannot
+    /// be submitted while it is invalid.
+    pub fn initial_value(mut self, value: impl Into<String>) -> Self {
+        self.initial_value = Some(value.into());
+        self
+    }
+
+    /// Sets the label of the OK button of the [`Prompt`](Prompt).
+    pub fn ok_label(mut self, label: impl Into<String>) -> Self {
+        self.ok_label = label.into();
+        self
+    }
+
+    /// Sets the label of the Cancel button of the [`Prompt`](Prompt).
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`Prompt`](Prompt).
+    pub fn max_width(mut self, width: u32) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Sets the [`Placement`](modal::Placement) of the [`Prompt`](Prompt).
+    /// 
+    /// The [`Prompt`](Prompt) is centered by default.
+    pub fn placement(mut self, placement: modal::Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Animation`](modal::Animation) of opening and closing the
+    /// [`Prompt`](Prompt).
+    /// 
+    /// The [`Prompt`](Prompt) appears and disappears instantly by default.
+    pub fn animation(mut self, animation: modal::Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets whether the focus of the underlay is restored when the
+    /// [`Prompt`](Prompt) is closed.
+    /// 
+    /// The focused widgets of the underlay keep their focus while the
+    /// [`Prompt`](Prompt) is shown by default.
+    pub fn restore_focus(mut self, restore: bool) -> Self {
+        self.restore_focus = restore;
+        self
+    }
+
+    /// Sets the style of the [`Modal`](Modal) showing the [`Prompt`](Prompt).
+    pub fn style(mut self, style: impl Into<<Renderer as modal::Renderer>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the [`Card`](Card) of the [`Prompt`](Prompt).
+    pub fn card_style<S>(mut self, style: S) -> Self
+    where
+        S: 'a + Clone + Into<<Renderer as card::Renderer>::Style>,
+    {
+        self.card_style = Box::new(move || style.clone().into());
+        self
+    }
+}
+
+/// The state of the widgets of a dialog.
+/// 
+/// It is held by the [`State`](crate::native::modal::State) of the
+/// [`Modal`](crate::native::modal::Modal) showing the dialog.
+#[derive(Debug, Default)]
+pub struct State {
+    ok: button::State,
+    cancel: button::State,
+    input: text_input::State,
+}
+
+impl State {
+    /// Creates a new [`State`](State).
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+/// Only the text input of a [`Prompt`](Prompt) is focusable.
+impl modal::Focus for State {
+    fn focusable_count(&self) -> usize {
+        1
+    }
+
+    fn focused(&self) -> Option<usize> {
+        if self.input.is_focused() { Some(0) } else { None }
+    }
+
+    fn focus(&mut self, _index: usize) {
+        self.input.focus();
+    }
+}
+
+/// Creates a button of the foot of a dialog filling its share of the width.
+/// 
+/// The button is disabled without a message.
+fn dialog_button<'a, Message, Renderer, S>(
+    state: &'a mut button::State,
+    label: &str,
+    style: S,
+    on_press: Option<Message>,
+) -> Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + button::Renderer + text::Renderer,
+    S: Into<<Renderer as button::Renderer>::Style>,
+{
+    let button = Button::new(
+        state,
+        Text::new(label).horizontal_alignment(HorizontalAlignment::Center),
+    )
+    .width(Length::Fill)
+    .style(style);
+
+    match on_press {
+        Some(on_press) => button.on_press(on_press).into(),
+        None => button.into(),
+    }
+}
+
+impl<'a, Message, Renderer> From<Alert<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + card::Renderer + modal::Renderer + container::Renderer
+        + button::Renderer + row::Renderer + text::Renderer,
+    <Renderer as button::Renderer>::Style: From<Primary>,
+{
+    fn from(alert: Alert<'a, Message, Renderer>) -> Self {
+        let Alert {
+            state,
+            underlay,
+            title,
+            message,
+            on_ok,
+            ok_label,
+            max_width,
+            placement,
+            animation,
+            restore_focus,
+            style,
+            card_style,
+        } = alert;
+
+        let on_dismiss = on_ok.clone();
+
+        let modal = Modal::new(
+            state,
+            underlay,
+            move |state| {
+                Card::new(Text::new(title.clone()), Text::new(message.clone()))
+                    .foot(
+                        Row::new()
+                            .spacing(10)
+                            .padding(5)
+                            .width(Length::Fill)
+                            .push(dialog_button(
+                                &mut state.ok,
+                                &ok_label,
+                                Primary,
+                                Some(on_ok.clone()),
+                            ))
+                    )
+                    .max_width(max_width)
+                    .on_close(on_ok.clone())
+                    .style(card_style())
+                    .into()
+            },
+        )
+        .backdrop(on_dismiss.clone())
+        .on_esc(on_dismiss)
+        .restore_focus(restore_focus)
+        .placement(placement)
+        .style(style);
+
+        match animation {
+            Some(animation) => modal.animation(animation).into(),
+            None => modal.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Confirm<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + card::Renderer + modal::Renderer + container::Renderer
+        + button::Renderer + row::Renderer + text::Renderer,
+    <Renderer as button::Renderer>::Style: From<Primary> + From<Secondary> + From<Danger>,
+{
+    fn from(confirm: Confirm<'a, Message, Renderer>) -> Self {
+        let Confirm {
+            state,
+            underlay,
+            title,
+            message,
+            on_ok,
+            on_cancel,
+            ok_label,
+            cancel_label,
+            is_danger,
+            max_width,
+            placement,
+            animation,
+            restore_focus,
+            style,
+            card_style,
+        } = confirm;
+
+        let on_dismiss = on_cancel.clone();
+
+        let modal = Modal::new(
+            state,
+            underlay,
+            move |state| {
+                let ok = if is_danger {
+                    dialog_button(&mut state.ok, &ok_label, Danger, Some(on_ok.clone()))
+                } else {
+                    dialog_button(&mut state.ok, &ok_label, Primary, Some(on_ok.clone()))
+                };
+
+                Card::new(Text::new(title.clone()), Text::new(message.clone()))
+                    .foot(
+                        Row::new()
+                            .spacing(10)
+                            .padding(5)
+                            .width(Length::Fill)
+                            .push(dialog_button(
+                                &mut state.cancel,
+                                &cancel_label,
+                                Secondary,
+                                Some(on_cancel.clone()),
+                            ))
+                            .push(ok)
+                    )
+                    .max_width(max_width)
+                    .on_close(on_cancel.clone())
+                    .style(card_style())
+                    .into()
+            },
+        )
+        .backdrop(on_dismiss.clone())
+        .on_esc(on_dismiss)
+        .restore_focus(restore_focus)
+        .placement(placement)
+        .style(style);
+
+        match animation {
+            Some(animation) => modal.animation(animation).into(),
+            None => modal.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Prompt<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'static + Clone,
+    Renderer: 'a + card::Renderer + modal::Renderer + container::Renderer
+        + button::Renderer + column::Renderer + row::Renderer + text::Renderer
+        + text_input::Renderer,
+    <Renderer as button::Renderer>::Style: From<Primary> + From<Secondary>,
+{
+    fn from(prompt: Prompt<'a, Message, Renderer>) -> Self {
+        let Prompt {
+            state,
+            underlay,
+            title,
+            message,
+            placeholder,
+            value,
+            on_input,
+            on_submit,
+            on_cancel,
+            validate,
+            initial_value,
+            ok_label,
+            cancel_label,
+            max_width,
+            placement,
+            animation,
+            restore_focus,
+            style,
+            card_style,
+        } = prompt;
+
+        // An invalid text cannot be submitted.
+        let error = validate.and_then(|validate| validate(&value).err());
+        let submit = if error.is_none() {
+            Some(on_submit(value.clone()))
+        } else {
+            None
+        };
+
+        // The initial text is not edited yet, so its error is hidden.
+        let error = error.filter(|_| initial_value.as_ref() != Some(&value));
+
+        let on_dismiss = on_cancel.clone();
+
+        let modal = Modal::new(
+            state,
+            underlay,
+            move |state| {
+                let on_input = Rc::clone(&on_input);
+                let mut input = TextInput::new(
+                    &mut state.input,
+                    &placeholder,
+                    &value,
+                    move |value| on_input(value),
+                )
+                .padding(5);
+
+                if let Some(submit) = submit.clone() {
+                    input = input.on_submit(submit);
+                }
+
+                let mut body = Column::new()
+                    .spacing(10)
+                    .width(Length::Fill);
+
+                if let Some(message) = message.as_ref() {
+                    body = body.push(Text::new(message.clone()));
+                }
+
+                body = body.push(input);
+
+                if let Some(error) = error.as_ref() {
+                    body = body.push(Text::new(error.clone()).color(colors::DANGER));
+                }
+
+                Card::new(Text::new(title.clone()), body)
+                    .foot(
+                        Row::new()
+                            .spacing(10)
+                            .padding(5)
+                            .width(Length::Fill)
+                            .push(dialog_button(
+                                &mut state.cancel,
+                                &cancel_label,
+                                Secondary,
+                                Some(on_cancel.clone()),
+                            ))
+                            .push(dialog_button(
+                                &mut state.ok,
+                                &ok_label,
+                                Primary,
+                                submit.clone(),
+                            ))
+                    )
+                    .max_width(max_width)
+                    .on_close(on_cancel.clone())
+                    .style(card_style())
+                    .into()
+            },
+        )
+        .backdrop(on_dismiss.clone())
+        .on_esc(on_dismiss)
+        .trap_focus()
+        .restore_focus(restore_focus)
+        .placement(placement)
+        .style(style);
+
+        match animation {
+            Some(animation) => modal.animation(animation).into(),
+            None => modal.into(),
+        }
+    }
+}
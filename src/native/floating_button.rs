@@ -15,6 +15,7 @@ pub mod offset;
 pub use offset::Offset;
 
 use super::overlay::floating_button::FloatingButtonOverlay;
+use crate::style::shadow::Shadow;
 
 /// A floating button floating over some content.
 /// 
@@ -48,6 +49,8 @@ where
     anchor: Anchor,
     offset: Offset,
     hidden: bool,
+    shadow: Option<Shadow>,
+    shadow_border_radius: f32,
     underlay: Element<'a, Message, Renderer>,
     button: B,
 }
@@ -69,6 +72,8 @@ where
             anchor: Anchor::SouthEast,
             offset: 5.0.into(),
             hidden: false,
+            shadow: None,
+            shadow_border_radius: 0.0,
             underlay: underlay.into(),
             button: button,
         }
@@ -96,6 +101,23 @@ where
         self
     }
 
+    /// Sets the drop [`Shadow`](crate::style::shadow::Shadow) of the
+    /// [`Button`](iced_native::button::Button) on the
+    /// [`FloatingButton`](FloatingButton).
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the border radius of the shadow of the
+    /// [`FloatingButton`](FloatingButton).
+    /// 
+    /// This should match the border radius of the style of the
+    /// [`Button`](iced_native::button::Button).
+    pub fn shadow_border_radius(mut self, border_radius: f32) -> Self {
+        self.shadow_border_radius = border_radius;
+        self
+    }
 }
 
 impl<'a, B, Message, Renderer> Widget<Message, Renderer>
@@ -192,6 +214,8 @@ where
                 &self.button,
                 &self.anchor,
                 &self.offset,
+                self.shadow,
+                self.shadow_border_radius,
             )
             .overlay(position)
         )
@@ -205,12 +229,16 @@ where
 pub trait Renderer: iced_native::Renderer {
 
     /// Draws a [`FloatingButton`](FloatingButton)
+    /// 
+    /// `shadow` holds the drop shadow of the floating element along with its
+    /// border radius.
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
         cursor_position: Point,
         layout: Layout<'_>,
         floating: &Element<'_, Message, Self>,
+        shadow: Option<(&Shadow, f32)>,
         viewport: &Rectangle,
     ) -> Self::Output;
 }
@@ -223,6 +251,7 @@ impl Renderer for iced_native::renderer::Null {
         _cursor_position: Point,
         _layout: Layout<'_>,
         _floating: &Element<'_, Message, Self>,
+        _shadow: Option<(&Shadow, f32)>,
         _viewport: &Rectangle,
     ) -> Self::Output {}
 }
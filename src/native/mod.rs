@@ -2,6 +2,10 @@
 
 pub mod overlay;
 
+/// The distance scrolled by a single line of the mouse wheel.
+#[cfg(any(feature = "card", feature = "tab_bar"))]
+pub(crate) const SCROLL_LINE_HEIGHT: f32 = 60.0;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
@@ -12,6 +16,9 @@ pub mod card;
 #[cfg(feature = "card")]
 pub use card::Card;
 
+#[cfg(feature = "dialog")]
+pub mod dialog;
+
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
@@ -29,7 +36,7 @@ pub mod tabs;
 
 #[doc(no_inline)]
 #[cfg(feature = "tab_bar")]
-pub use tab_bar::{TabBar, TabLabel};
+pub use tab_bar::{TabBadge, TabBar, TabLabel};
 
 #[doc(no_inline)]
 #[cfg(feature = "tabs")]
@@ -1,18 +1,36 @@
 //! A modal for showing elements as an overlay on top of another.
 //! 
 //! *This API requires the following crate features to be activated: modal*
-use std::hash::Hash;
+use std::{hash::Hash, time::{Duration, Instant}};
 
-use iced_native::{Clipboard, Element, Event, Layout, Point, Widget, event, overlay};
+use iced_native::{Clipboard, Element, Event, Layout, Point, Widget, event, keyboard, mouse, overlay};
 
 use super::overlay::modal::{self, ModalOverlay};
-pub use super::overlay::modal::Renderer;
+pub use super::overlay::modal::{Placement, Renderer};
 
 /// A modal content as an overlay.
 /// 
 /// Can be used in combination with the [`Card`](crate::native::card::Card)
 /// widget to form dialog elements.
 /// 
+/// Modals can be stacked by using a [`Modal`](Modal) as the underlay of
+/// another one. The outer [`Modal`](Modal) is shown on top of the inner one
+/// and dims it with its backdrop. While it is shown, only the topmost
+/// [`Modal`](Modal) receives events, so its [`on_esc`](Modal::on_esc) and
+/// [`backdrop`](Modal::backdrop) messages are the only ones produced.
+/// 
+/// While the [`Modal`](Modal) is shown, the underlay does not receive any
+/// keyboard or mouse events. If the state of the content implements
+/// [`Focus`](Focus), [`trap_focus`](Modal::trap_focus) lets the Tab key
+/// cycle the focus through the content.
+/// 
+/// With an [`animation`](Modal::animation), the backdrop fades in while the
+/// content slides in when the [`Modal`](Modal) is opened and both are
+/// reversed when it is closed. The overlay is kept until the close animation
+/// finished. Iced only redraws after events, so keep your application
+/// redrawing while [`State::is_animating`](State::is_animating), e.g. by
+/// subscribing to `iced::time::every`.
+/// 
 /// # Example
 /// ```
 /// # use iced_aw::native::modal;
@@ -47,6 +65,9 @@ where
     content: Content,
     backdrop: Option<Message>,
     esc: Option<Message>,
+    cycle_focus: Option<fn(&mut S, Option<bool>)>,
+    restore_focus: bool,
+    placement: Placement,
     style: Renderer::Style,
 }
 
@@ -70,12 +91,18 @@ where
     where
         U: Into<Element<'a, Message, Renderer>>,
     {
+        // The state only remembers the animation of the latest view.
+        state.animation = None;
+
         Modal {
             state,
             underlay: underlay.into(),
             content,
             backdrop: None,
             esc: None,
+            cycle_focus: None,
+            restore_focus: true,
+            placement: Placement::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -96,6 +123,36 @@ where
         self
     }
 
+    /// Sets whether the focus of the underlay is restored when the
+    /// [`Modal`](Modal) is closed.
+    /// 
+    /// The focused widgets of the underlay keep their focus while the
+    /// [`Modal`](Modal) is shown by default. Otherwise they lose their focus
+    /// when the [`Modal`](Modal) is opened. Any messages the underlay
+    /// produces while losing its focus are discarded.
+    pub fn restore_focus(mut self, restore: bool) -> Self {
+        self.restore_focus = restore;
+        self
+    }
+
+    /// Sets the [`Placement`](Placement) of the content of the
+    /// [`Modal`](Modal), like a bottom sheet or a side drawer.
+    /// 
+    /// The content is centered by default.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Animation`](Animation) of opening and closing the
+    /// [`Modal`](Modal).
+    /// 
+    /// The [`Modal`](Modal) appears and disappears instantly by default.
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.state.animation = Some(animation);
+        self
+    }
+
     /// Sets the style of the [`Modal`](Modal).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -103,10 +160,139 @@ where
     }
 }
 
+impl<'a, S, Content, Message, Renderer> Modal<'a, S, Content, Message, Renderer>
+where 
+    S: 'a + Focus,
+    Content: Fn(&mut S) -> Element<'_, Message, Renderer>,
+    Message: Clone,
+    Renderer: modal::Renderer,
+{
+    /// Traps the focus inside of the content of the [`Modal`](Modal).
+    /// 
+    /// While the [`Modal`](Modal) is shown, Tab and Shift+Tab cycle the
+    /// focus through the focusable widgets of the content and the first one
+    /// is focused when the [`Modal`](Modal) is opened.
+    pub fn trap_focus(mut self) -> Self {
+        self.cycle_focus = Some(cycle_focus::<S>);
+        self
+    }
+}
+
+/// Moves the focus of the given [`Focus`](Focus) to the next focusable
+/// widget, or to the previous one if `forward` is false.
+/// 
+/// Without a direction, the first widget is focused if no widget has the
+/// focus yet.
+fn cycle_focus<S: Focus>(state: &mut S, forward: Option<bool>) {
+    let count = state.focusable_count();
+
+    if count == 0 {
+        return;
+    }
+
+    let index = match (state.focused(), forward) {
+        (Some(_), None) => return,
+        (Some(index), Some(true)) => (index + 1) % count,
+        (Some(index), Some(false)) => (index + count - 1) % count,
+        (None, Some(false)) => count - 1,
+        (None, _) => 0,
+    };
+
+    state.focus(index);
+}
+
+/// The [`Animation`](Animation) of opening and closing a [`Modal`](Modal).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animation {
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`](Animation) taking the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Animation {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`](Easing) of the [`Animation`](Animation).
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Animation::new(Duration::from_millis(200))
+    }
+}
+
+/// The easing function of an [`Animation`](Animation) mapping the elapsed
+/// time to its progress.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum Easing {
+    /// The progress increases at a constant speed.
+    Linear,
+
+    /// The progress starts slowly and speeds up.
+    EaseIn,
+
+    /// The progress starts quickly and slows down.
+    EaseOut,
+
+    /// The progress starts slowly, speeds up and slows down again.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the progress of an [`Animation`](Animation) after the given
+    /// fraction of its duration.
+    fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+            },
+        }
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::EaseOut
+    }
+}
+
+/// The state of the content of a [`Modal`](Modal) holding focusable
+/// widgets, like the states of text inputs.
+/// 
+/// Implementing this allows the [`Modal`](Modal) to
+/// [`trap_focus`](Modal::trap_focus) inside of its content.
+pub trait Focus {
+    /// Returns the number of focusable widgets of the content.
+    fn focusable_count(&self) -> usize;
+
+    /// Returns the index of the focused widget of the content, if any.
+    fn focused(&self) -> Option<usize>;
+
+    /// Focuses the widget with the given index and unfocuses all others.
+    fn focus(&mut self, index: usize);
+}
+
 /// The state of the modal.
 #[derive(Debug)]
 pub struct State<S> {
     show: bool,
+    is_opened: bool,
+    animation: Option<Animation>,
+    changed_at: Option<Instant>,
     state: S,
 }
 
@@ -115,6 +301,9 @@ impl<S> State<S> {
     pub fn new(s: S) -> Self {
         State {
             show: false,
+            is_opened: false,
+            animation: None,
+            changed_at: None,
             state: s,
         }
     }
@@ -122,7 +311,46 @@ impl<S> State<S> {
     /// Setting this to true shows the modal (the modal is open), false means
     /// the modal is hidden (closed).
     pub fn show(&mut self, b: bool) {
+        if self.show == b {
+            return;
+        }
+
+        let now = Instant::now();
+
+        // An interrupted animation is reversed from where it stopped.
+        let remaining = self.animation.zip(self.changed_at)
+            .and_then(|(animation, changed_at)| {
+                animation.duration.checked_sub(now.duration_since(changed_at))
+            })
+            .unwrap_or_default();
+
         self.show = b;
+        self.changed_at = Some(now.checked_sub(remaining).unwrap_or(now));
+    }
+
+    /// Returns true if the modal is currently opening or closing.
+    /// 
+    /// The application needs to be redrawn continuously while this is true
+    /// to show the [`Animation`](Animation) of the [`Modal`](Modal).
+    pub fn is_animating(&self) -> bool {
+        self.animation.zip(self.changed_at)
+            .map_or(false, |(animation, changed_at)| {
+                changed_at.elapsed() < animation.duration
+            })
+    }
+
+    /// Returns the progress of opening the modal between 0.0 (closed) and
+    /// 1.0 (open).
+    fn progress(&self) -> f32 {
+        let progress = match self.animation.zip(self.changed_at) {
+            Some((animation, changed_at)) if animation.duration > Duration::from_secs(0) => {
+                let t = changed_at.elapsed().as_secs_f32() / animation.duration.as_secs_f32();
+                animation.easing.apply(t.min(1.0))
+            },
+            _ => 1.0,
+        };
+
+        if self.show { progress } else { 1.0 - progress }
     }
 }
 
@@ -159,14 +387,68 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>
     ) -> event::Status {
-        self.underlay.on_event(
-            event,
-            layout,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
-        )
+        if !self.state.show {
+            self.state.is_opened = false;
+
+            return self.underlay.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+
+        // The modal was opened since the last event.
+        let is_opening = !self.state.is_opened;
+        if is_opening {
+            self.state.is_opened = true;
+
+            if let Some(cycle_focus) = self.cycle_focus {
+                cycle_focus(&mut self.state.state, None);
+            }
+
+            // A press outside of every widget takes away their focus. The
+            // messages produced by the fake press are dropped.
+            if !self.restore_focus {
+                let mut ignored = Vec::new();
+                let _ = self.underlay.on_event(
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                    layout,
+                    Point::new(-1.0, -1.0),
+                    &mut ignored,
+                    renderer,
+                    clipboard,
+                );
+            }
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) => match self.cycle_focus {
+                Some(cycle_focus) => {
+                    // The first widget was just focused by opening the modal.
+                    if !is_opening {
+                        cycle_focus(&mut self.state.state, Some(!modifiers.shift));
+                    }
+                    event::Status::Captured
+                },
+                None => event::Status::Ignored,
+            },
+            // The underlay still needs to know about changes of the window.
+            Event::Keyboard(_) | Event::Mouse(_) => event::Status::Ignored,
+            _ => self.underlay.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            ),
+        }
     }
 
     fn draw(
@@ -195,11 +477,16 @@ where
     }
 
     fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if !self.state.show { return self.underlay.overlay(layout); }
+        // A closed modal stays until its close animation finished.
+        let progress = self.state.progress();
+        if !self.state.show && progress <= 0.0 { return self.underlay.overlay(layout); }
 
         let bounds = layout.bounds();
         let position = Point::new(bounds.x, bounds.y);
 
+        // A modal wrapped by this one is shown below it.
+        let below = self.underlay.overlay(layout);
+
         Some(
             ModalOverlay::new(
                 &mut self.state.state,
@@ -208,6 +495,10 @@ where
                 self.esc.clone(),
                 &self.style,
             )
+            .placement(self.placement)
+            .progress(progress)
+            .closing(!self.state.show)
+            .on_top_of(below)
             .overlay(position)
         )
     }
@@ -224,4 +515,115 @@ where
     fn from(modal: Modal<'a, State, Content, Message, Renderer>) -> Self {
         Element::new(modal)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A content with three focusable widgets.
+    #[derive(Default)]
+    struct Inputs {
+        focused: Option<usize>,
+    }
+
+    impl Focus for Inputs {
+        fn focusable_count(&self) -> usize {
+            3
+        }
+
+        fn focused(&self) -> Option<usize> {
+            self.focused
+        }
+
+        fn focus(&mut self, index: usize) {
+            self.focused = Some(index);
+        }
+    }
+
+    #[test]
+    fn easing_starts_at_zero_and_ends_at_one() {
+        for easing in [Easing::Linear, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut].iter() {
+            assert!(easing.apply(0.0).abs() < f32::EPSILON);
+            assert!((easing.apply(1.0) - 1.0).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn easing_shapes_the_progress() {
+        assert!(Easing::EaseIn.apply(0.25) < Easing::Linear.apply(0.25));
+        assert!(Easing::EaseOut.apply(0.25) > Easing::Linear.apply(0.25));
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < f32::EPSILON);
+        assert!(Easing::EaseInOut.apply(0.25) < 0.25);
+        assert!(Easing::EaseInOut.apply(0.75) > 0.75);
+    }
+
+    #[test]
+    fn show_without_animation_is_instant() {
+        let mut state = State::new(());
+
+        state.show(true);
+        assert!((state.progress() - 1.0).abs() < f32::EPSILON);
+        assert!(!state.is_animating());
+
+        state.show(false);
+        assert!(state.progress().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn show_reverses_an_interrupted_animation() {
+        let mut state = State::new(());
+        state.animation = Some(Animation::new(Duration::from_secs(10)).easing(Easing::Linear));
+
+        state.show(true);
+
+        // Interrupt the opening at 30 percent.
+        state.changed_at = Some(Instant::now() - Duration::from_secs(3));
+        assert!((state.progress() - 0.3).abs() < 0.01);
+
+        state.show(false);
+        assert!(state.is_animating());
+        assert!((state.progress() - 0.3).abs() < 0.01);
+    }
+
+    #[test]
+    fn show_ignores_an_unchanged_value() {
+        let mut state = State::new(());
+        state.animation = Some(Animation::default());
+
+        state.show(true);
+        let changed_at = state.changed_at;
+
+        state.show(true);
+        assert_eq!(state.changed_at, changed_at);
+    }
+
+    #[test]
+    fn cycle_focus_focuses_the_first_widget_when_opened() {
+        let mut inputs = Inputs::default();
+
+        cycle_focus(&mut inputs, None);
+        assert_eq!(inputs.focused, Some(0));
+
+        // An already focused widget keeps its focus.
+        inputs.focused = Some(2);
+        cycle_focus(&mut inputs, None);
+        assert_eq!(inputs.focused, Some(2));
+    }
+
+    #[test]
+    fn cycle_focus_wraps_around() {
+        let mut inputs = Inputs { focused: Some(2) };
+
+        cycle_focus(&mut inputs, Some(true));
+        assert_eq!(inputs.focused, Some(0));
+
+        cycle_focus(&mut inputs, Some(false));
+        assert_eq!(inputs.focused, Some(2));
+
+        let mut inputs = Inputs::default();
+
+        cycle_focus(&mut inputs, Some(false));
+        assert_eq!(inputs.focused, Some(2));
+    }
+}
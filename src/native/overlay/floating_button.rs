@@ -5,11 +5,12 @@
 use std::hash::Hash;
 
 use iced_native::{
-    button, event, layout::Limits, overlay, Button, Clipboard, Event,
-    Layout, Point, Size, Widget, 
+    button, event, layout::Limits, overlay, Button, Clipboard, Element, Event,
+    Layout, Point, Size, 
 };
 
-use crate::native::floating_button::{Anchor, Offset};
+use crate::native::floating_button::{self, Anchor, Offset};
+use crate::style::shadow::Shadow;
 
 /// The internal overlay of a [`FloatingButton`](crate::native::FloatingButton) for
 /// rendering a [`Button`](iced_native::button::Button) as an overlay.
@@ -22,13 +23,15 @@ where
     button: B,
     anchor: &'a Anchor,
     offset: &'a Offset,
+    shadow: Option<Shadow>,
+    shadow_border_radius: f32,
 }
 
 impl<'a, B, Message, Renderer> FloatingButtonOverlay<'a, B, Message, Renderer>
 where
     B: Fn(&mut button::State) -> Button<'_, Message, Renderer>,
     Message: Clone + 'a,
-    Renderer: iced_native::button::Renderer + 'a,
+    Renderer: iced_native::button::Renderer + floating_button::Renderer + 'a,
 {
     /// Creates a new [`FloatingButtonOverlay`] containing the given
     /// [`Button`](iced_native::button::Button).
@@ -37,6 +40,8 @@ where
         button: B,
         anchor: &'a Anchor,
         offset: &'a Offset,
+        shadow: Option<Shadow>,
+        shadow_border_radius: f32,
     ) -> Self
     {
         FloatingButtonOverlay {
@@ -44,6 +49,8 @@ where
             button,
             anchor,
             offset,
+            shadow,
+            shadow_border_radius,
         }
     }
 
@@ -65,7 +72,9 @@ where
 struct Overlay<'a, Message, Renderer: iced_native::button::Renderer> {
     anchor: &'a Anchor,
     offset: &'a Offset,
-    button: Button<'a, Message, Renderer>,
+    shadow: Option<Shadow>,
+    shadow_border_radius: f32,
+    button: Element<'a, Message, Renderer>,
 }
 
 impl<'a, Message, Renderer: iced_native::button::Renderer> Overlay<'a, Message, Renderer>
@@ -82,12 +91,16 @@ where
             button,
             anchor,
             offset,
+            shadow,
+            shadow_border_radius,
         } = floating_button;
 
         Self {
             anchor,
             offset,
-            button: button(state),
+            shadow,
+            shadow_border_radius,
+            button: button(state).into(),
         }
     }
 }
@@ -96,7 +109,7 @@ impl<'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
     for Overlay<'a, Message, Renderer>
 where
     Message: Clone + 'a,
-    Renderer: iced_native::button::Renderer + 'a,
+    Renderer: iced_native::button::Renderer + floating_button::Renderer + 'a,
 {
     fn layout(
         &self,
@@ -168,11 +181,13 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
-        self.button.draw(
+        floating_button::Renderer::draw(
             renderer,
             defaults,
-            layout,
             cursor_position,
+            layout,
+            &self.button,
+            self.shadow.as_ref().map(|shadow| (shadow, self.shadow_border_radius)),
             &layout.bounds(),
         )
     }
@@ -8,4 +8,13 @@ pub use floating_button::FloatingButtonOverlay;
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
-pub use modal::ModalOverlay;
\ No newline at end of file
+pub use modal::ModalOverlay;
+
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar_menu;
+#[cfg(feature = "tab_bar")]
+pub use tab_bar_menu::TabBarMenuOverlay;
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar_tooltip;
+#[cfg(feature = "tab_bar")]
+pub use tab_bar_tooltip::TabBarTooltipOverlay;
\ No newline at end of file
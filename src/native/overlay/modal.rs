@@ -4,7 +4,7 @@
 
 use std::hash::Hash;
 
-use iced_native::{Clipboard, Container, Element, Event, Layout, Length, Point, Size, event, keyboard, layout::Limits, mouse, overlay};
+use iced_native::{Clipboard, Element, Event, Layout, Point, Size, event, keyboard, layout::Limits, mouse, overlay};
 
 /// The overlay of the modal.
 #[allow(missing_debug_implementations)]
@@ -19,7 +19,11 @@ where
     content: Content,
     backdrop: Option<Message>,
     esc: Option<Message>,
+    placement: Placement,
+    progress: f32,
+    is_closing: bool,
     style: &'a <Renderer as self::Renderer>::Style,
+    below: Option<overlay::Element<'a, Message, Renderer>>,
 }
 
 impl<'a, State, Content, Message, Renderer> ModalOverlay<'a, State, Content, Message, Renderer>
@@ -42,10 +46,50 @@ where
             content,
             backdrop,
             esc,
+            placement: Placement::default(),
+            progress: 1.0,
+            is_closing: false,
             style,
+            below: None,
         }
     }
 
+    /// Sets the [`Placement`](Placement) of the content of the
+    /// [`ModalOverlay`](ModalOverlay).
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the progress of the open animation of the
+    /// [`ModalOverlay`](ModalOverlay) between 0.0 (closed) and 1.0 (open).
+    /// 
+    /// The backdrop fades in and the content slides in from the edge it is
+    /// placed at while the progress increases.
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = progress.max(0.0).min(1.0);
+        self
+    }
+
+    /// Sets whether the [`ModalOverlay`](ModalOverlay) is closing.
+    /// 
+    /// A closing [`ModalOverlay`](ModalOverlay) is only drawn until its
+    /// close animation finished and ignores every event.
+    pub fn closing(mut self, is_closing: bool) -> Self {
+        self.is_closing = is_closing;
+        self
+    }
+
+    /// Stacks the [`ModalOverlay`](ModalOverlay) on top of the given overlay
+    /// of a lower level.
+    /// 
+    /// The lower overlay is drawn below the backdrop and does not receive
+    /// any events while the [`ModalOverlay`](ModalOverlay) is shown.
+    pub fn on_top_of(mut self, below: Option<overlay::Element<'a, Message, Renderer>>) -> Self {
+        self.below = below;
+        self
+    }
+
     /// Turn this [`ModalOverlay`] into an [`Overlay`](Overlay).
     pub fn overlay(
         self,
@@ -63,7 +107,11 @@ struct Overlay<'a, Message, Renderer: self::Renderer/* + iced_native::container:
     content: Element<'a, Message, Renderer>,
     backdrop: Option<Message>,
     esc: Option<Message>,
+    placement: Placement,
+    progress: f32,
+    is_closing: bool,
     style: &'a <Renderer as self::Renderer>::Style,
+    below: Option<overlay::Element<'a, Message, Renderer>>,
 }
 
 impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
@@ -80,19 +128,22 @@ where
             content,
             backdrop,
             esc,
+            placement,
+            progress,
+            is_closing,
             style,
+            below,
         } = modal;
         
         Self {
-            content: Container::new(content(state))
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .center_x()
-                .center_y()
-                .into(),
+            content: content(state),
             backdrop,
             esc,
+            placement,
+            progress,
+            is_closing,
             style,
+            below,
         }
     }
 }
@@ -109,16 +160,58 @@ where
         bounds: iced_graphics::Size,
         position: Point,
     ) -> iced_native::layout::Node {
+        // Bottom sheets fill the width and side drawers fill the height of
+        // the window.
+        let min = match self.placement {
+            Placement::Bottom => Size::new(bounds.width, 0.0),
+            Placement::Left | Placement::Right => Size::new(0.0, bounds.height),
+            Placement::Center | Placement::Top => Size::ZERO,
+        };
+
         let limits = Limits::new(
-            Size::ZERO,
+            min,
             bounds,
         );
         
-        let mut content = self.content.layout(renderer, &limits);
+        let mut modal = self.content.layout(renderer, &limits);
+        let size = modal.size();
+
+        // The content slides in from the edge it is placed at. Centered
+        // content slides up by a quarter of its height.
+        let hidden = 1.0 - self.progress;
+
+        modal.move_to(match self.placement {
+            Placement::Center => Point::new(
+                (bounds.width - size.width) / 2.0,
+                (bounds.height - size.height) / 2.0 + hidden * size.height / 4.0,
+            ),
+            Placement::Top => Point::new(
+                (bounds.width - size.width) / 2.0,
+                -hidden * size.height,
+            ),
+            Placement::Bottom => Point::new(
+                0.0,
+                bounds.height - size.height + hidden * size.height,
+            ),
+            Placement::Left => Point::new(-hidden * size.width, 0.0),
+            Placement::Right => Point::new(
+                bounds.width - size.width + hidden * size.width,
+                0.0,
+            ),
+        });
+
+        // The content spans the whole window to catch the backdrop clicks.
+        let mut content = iced_native::layout::Node::with_children(bounds, vec![modal]);
 
         content.move_to(position);
 
-        content
+        // The overlay of the lower level is placed next to the content.
+        iced_native::layout::Node::with_children(
+            bounds,
+            std::iter::once(content)
+                .chain(self.below.as_ref().map(|below| below.layout(renderer, bounds)))
+                .collect(),
+        )
     }
 
     fn on_event(
@@ -130,6 +223,13 @@ where
         renderer: &Renderer,
         clipboard: Option<&dyn Clipboard>
     ) -> event::Status {
+        if self.is_closing {
+            return event::Status::Ignored;
+        }
+
+        // Only the topmost level of a stack receives events.
+        let layout = layout.children().next().unwrap();
+
         // TODO clean this up
         let esc_status = self.esc.as_ref()
             .map(|esc| {
@@ -169,7 +269,7 @@ where
         match esc_status.merge(backdrop_status) {
             event::Status::Ignored => self.content.on_event(
                 event,
-                layout,
+                layout.children().next().unwrap(),
                 cursor_position,
                 messages,
                 renderer,
@@ -187,12 +287,17 @@ where
         layout: iced_native::Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+
         renderer.draw(
             defaults,
             cursor_position,
             &self.style,
             &self.content,
-            layout
+            content_layout,
+            self.progress,
+            self.below.as_ref().zip(children.next()),
         )
     }
 
@@ -202,7 +307,40 @@ where
 
         (position.x as u32).hash(state);
         (position.y as u32).hash(state);
+        self.placement.hash(state);
+        ((self.progress * 1000.0) as u32).hash(state);
         self.content.hash_layout(state);
+        if let Some(below) = self.below.as_ref() { below.hash_layout(state) };
+    }
+}
+
+/// The placement of the content of a [`ModalOverlay`](ModalOverlay) in the
+/// window.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum Placement {
+    /// The content keeps its size and is centered in the window.
+    Center,
+
+    /// The content keeps its size and drops down from the top edge of the
+    /// window, centered horizontally.
+    Top,
+
+    /// The content is placed as a sheet at the bottom edge of the window,
+    /// filling its width.
+    Bottom,
+
+    /// The content is placed as a drawer at the left edge of the window,
+    /// filling its height.
+    Left,
+
+    /// The content is placed as a drawer at the right edge of the window,
+    /// filling its height.
+    Right,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement::Center
     }
 }
 
@@ -216,6 +354,15 @@ pub trait Renderer: iced_native::Renderer {
     type Style: Default;
 
     /// Draws a [`ModalOverlay`](ModalOverlay).
+    /// 
+    /// The first child of the `layout` spanning the whole window is the
+    /// layout of the `modal` content. `below` holds the overlay of a lower
+    /// level of a stack of modals along with its layout. It has to be drawn
+    /// below the backdrop.
+    /// 
+    /// `progress` is the progress of the open or close animation between
+    /// 0.0 (closed) and 1.0 (open). The backdrop and the shadow of the
+    /// content have to be faded in accordingly.
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -223,6 +370,8 @@ pub trait Renderer: iced_native::Renderer {
         style_sheet: &Self::Style,
         modal: &Element<'_, Message, Self>,
         layout: Layout<'_>,
+        progress: f32,
+        below: Option<(&overlay::Element<'_, Message, Self>, Layout<'_>)>,
     ) -> Self::Output;
 }
 
@@ -236,5 +385,7 @@ impl Renderer for iced_native::renderer::Null {
         _style_sheet: &Self::Style,
         _modal: &Element<'_, Message, Self>,
         _layout: Layout<'_>,
+        _progress: f32,
+        _below: Option<(&overlay::Element<'_, Message, Self>, Layout<'_>)>,
     ) -> Self::Output {}
 }
\ No newline at end of file
@@ -0,0 +1,293 @@
+//! A menu listing all tabs of a [`TabBar`](crate::native::tab_bar::TabBar)
+//! as an overlay.
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+use std::hash::Hash;
+
+use iced_native::{
+    Align, Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle,
+    Row, Size, Text, column, event, keyboard, layout, mouse, overlay, row, text
+};
+
+use crate::native::tab_bar::{self, Tab, TabLabel, TabStatus};
+
+/// The distance the menu is scrolled by a single line of the mouse wheel.
+const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+/// The overlay of the menu of a [`TabBar`](crate::native::tab_bar::TabBar)
+/// listing all of its tabs.
+#[allow(missing_debug_implementations)]
+pub struct TabBarMenuOverlay<'b, 'a, Message, Renderer>
+where
+    Renderer: tab_bar::Renderer,
+{
+    state: &'b mut tab_bar::State,
+    tabs: &'b [Tab<'a, Message, Renderer>],
+    tab_statuses: Vec<TabStatus>,
+    active_tab: usize,
+    on_select: &'b dyn Fn(usize) -> Message,
+    button_bounds: Rectangle,
+    text_size: u16,
+    padding: u16,
+    icon_font: Option<Font>,
+    text_font: Option<Font>,
+    style: &'b <Renderer as tab_bar::Renderer>::Style,
+}
+
+impl<'b, 'a, Message, Renderer> TabBarMenuOverlay<'b, 'a, Message, Renderer>
+where
+    Message: 'b,
+    Renderer: 'b + tab_bar::Renderer + column::Renderer + text::Renderer
+        + row::Renderer,
+{
+    /// Creates a new [`TabBarMenuOverlay`](TabBarMenuOverlay) for the given
+    /// tabs, opened by the button with the given bounds.
+    pub fn new(
+        state: &'b mut tab_bar::State,
+        tabs: &'b [Tab<'a, Message, Renderer>],
+        tab_statuses: Vec<TabStatus>,
+        active_tab: usize,
+        on_select: &'b dyn Fn(usize) -> Message,
+        button_bounds: Rectangle,
+        text_size: u16,
+        padding: u16,
+        icon_font: Option<Font>,
+        text_font: Option<Font>,
+        style: &'b <Renderer as tab_bar::Renderer>::Style,
+    ) -> Self {
+        TabBarMenuOverlay {
+            state,
+            tabs,
+            tab_statuses,
+            active_tab,
+            on_select,
+            button_bounds,
+            text_size,
+            padding,
+            icon_font,
+            text_font,
+            style,
+        }
+    }
+
+    /// Turn this [`TabBarMenuOverlay`](TabBarMenuOverlay) into an overlay
+    /// [`Element`](overlay::Element).
+    pub fn overlay(self, position: Point) -> overlay::Element<'b, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    /// Returns the [`Row`](iced_native::Row) used to lay out the entry of the
+    /// given [`TabLabel`](TabLabel).
+    fn label_row<'c>(&self, tab_label: &TabLabel) -> Row<'c, Message, Renderer> {
+        let row = Row::new()
+            .align_items(Align::Center)
+            .padding(self.padding)
+            .spacing(self.padding);
+
+        match tab_label {
+            TabLabel::Icon(_icon) => row.push(
+                Row::new()
+                    .width(Length::Units(self.text_size))
+                    .height(Length::Units(self.text_size))
+            ),
+            TabLabel::Text(text) => row.push(
+                Text::new(text).size(self.text_size)
+            ),
+            TabLabel::IconText(_icon, text) => row
+                .push(
+                    Row::new()
+                        .width(Length::Units(self.text_size))
+                        .height(Length::Units(self.text_size))
+                )
+                .push(
+                    Text::new(text).size(self.text_size)
+                ),
+            TabLabel::WithBadge(label, _badge) => self.label_row(label),
+        }
+    }
+
+    /// Returns the maximum scroll offset of the menu.
+    fn max_scroll_offset(layout: Layout<'_>) -> f32 {
+        let bounds = layout.bounds();
+
+        layout.children()
+            .last()
+            .map(|entry| {
+                let entry = entry.bounds();
+
+                (entry.y + entry.height - bounds.y - bounds.height).max(0.0)
+            })
+            .unwrap_or(0.0)
+    }
+}
+
+impl<'b, 'a, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for TabBarMenuOverlay<'b, 'a, Message, Renderer>
+where
+    Message: 'b,
+    Renderer: 'b + tab_bar::Renderer + column::Renderer + text::Renderer
+        + row::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let entries: Vec<layout::Node> = self.tabs.iter()
+            .map(|tab| match tab {
+                Tab::Label(tab_label) => {
+                    self.label_row(tab_label).layout(renderer, &limits)
+                },
+                Tab::Element(element) => {
+                    let mut node = element.layout(renderer, &limits);
+                    node.move_to(Point::new(padding, padding));
+                    let size = node.size();
+
+                    layout::Node::with_children(
+                        Size::new(size.width + 2.0 * padding, size.height + 2.0 * padding),
+                        vec![node],
+                    )
+                },
+            })
+            .collect();
+
+        let width = entries.iter()
+            .map(|entry| entry.size().width)
+            .fold(0.0, f32::max);
+
+        let mut height = 0.0;
+        let entries: Vec<layout::Node> = entries.iter()
+            .map(|entry| {
+                let mut node = layout::Node::with_children(
+                    Size::new(width, entry.size().height),
+                    entry.children().to_vec(),
+                );
+                node.move_to(Point::new(0.0, height));
+                height += entry.size().height;
+
+                node
+            })
+            .collect();
+
+        // Keep the menu inside of the window.
+        let height = height.min((bounds.height - position.y).max(0.0));
+        let x = if position.x + width > bounds.width {
+            (bounds.width - width).max(0.0)
+        } else {
+            position.x
+        };
+
+        let mut node = layout::Node::with_children(Size::new(width, height), entries);
+        node.move_to(Point::new(x, position.y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !bounds.contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * SCROLL_LINE_HEIGHT,
+                    mouse::ScrollDelta::Pixels { y, .. } => -y,
+                };
+
+                self.state.menu_scroll_offset = (self.state.menu_scroll_offset + delta)
+                    .max(0.0)
+                    .min(Self::max_scroll_offset(layout));
+
+                event::Status::Captured
+            },
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if bounds.contains(cursor_position) {
+                    let cursor_position = Point::new(
+                        cursor_position.x,
+                        cursor_position.y + self.state.menu_scroll_offset,
+                    );
+
+                    let selected = layout.children()
+                        .position(|entry| entry.bounds().contains(cursor_position))
+                        .filter(|index| {
+                            !self.tab_statuses.get(*index)
+                                .map_or(false, |status| status.is_disabled)
+                        });
+
+                    if let Some(index) = selected {
+                        messages.push((self.on_select)(index));
+                        self.state.menu_open = false;
+                    }
+
+                    event::Status::Captured
+                } else {
+                    // The button toggles the menu by itself.
+                    if !self.button_bounds.contains(cursor_position) {
+                        self.state.menu_open = false;
+                    }
+
+                    event::Status::Ignored
+                }
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape, ..
+            }) => {
+                self.state.menu_open = false;
+
+                event::Status::Captured
+            },
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        tab_bar::Renderer::draw_menu(
+            renderer,
+            defaults,
+            self.active_tab,
+            self.tabs,
+            &self.tab_statuses,
+            layout,
+            cursor_position,
+            self.icon_font,
+            self.text_font,
+            self.state.menu_scroll_offset,
+            self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.tabs.iter().for_each(|tab| match tab {
+            Tab::Label(tab_label) => tab_label.hash(state),
+            Tab::Element(element) => element.hash_layout(state),
+        });
+        self.text_size.hash(state);
+        self.padding.hash(state);
+    }
+}
\ No newline at end of file
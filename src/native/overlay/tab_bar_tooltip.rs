@@ -0,0 +1,134 @@
+//! A tooltip showing the full text of a truncated tab of a
+//! [`TabBar`](crate::native::tab_bar::TabBar) as an overlay.
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+use std::hash::Hash;
+
+use iced_native::{
+    Clipboard, Event, Font, Layout, Point, Size, event, layout, overlay, text
+};
+
+use crate::native::tab_bar;
+
+/// The overlay of a [`TabBar`](crate::native::tab_bar::TabBar) showing the
+/// full text of a hovered tab whose label is truncated.
+#[allow(missing_debug_implementations)]
+pub struct TabBarTooltipOverlay<'b, Renderer>
+where
+    Renderer: tab_bar::Renderer,
+{
+    text: &'b str,
+    text_size: u16,
+    padding: u16,
+    text_font: Option<Font>,
+    style: &'b <Renderer as tab_bar::Renderer>::Style,
+}
+
+impl<'b, Renderer> TabBarTooltipOverlay<'b, Renderer>
+where
+    Renderer: 'b + tab_bar::Renderer + text::Renderer,
+{
+    /// Creates a new [`TabBarTooltipOverlay`](TabBarTooltipOverlay) for the
+    /// given text of a truncated tab label.
+    pub fn new(
+        text: &'b str,
+        text_size: u16,
+        padding: u16,
+        text_font: Option<Font>,
+        style: &'b <Renderer as tab_bar::Renderer>::Style,
+    ) -> Self {
+        TabBarTooltipOverlay {
+            text,
+            text_size,
+            padding,
+            text_font,
+            style,
+        }
+    }
+
+    /// Turn this [`TabBarTooltipOverlay`](TabBarTooltipOverlay) into an
+    /// overlay [`Element`](overlay::Element).
+    pub fn overlay<Message>(self, position: Point) -> overlay::Element<'b, Message, Renderer>
+    where
+        Message: 'b,
+    {
+        overlay::Element::new(position, Box::new(self))
+    }
+}
+
+impl<'b, Message, Renderer> iced_native::Overlay<Message, Renderer>
+    for TabBarTooltipOverlay<'b, Renderer>
+where
+    Message: 'b,
+    Renderer: 'b + tab_bar::Renderer + text::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let (width, height) = renderer.measure(
+            self.text,
+            self.text_size,
+            self.text_font.unwrap_or_default(),
+            Size::INFINITY,
+        );
+
+        let padding = f32::from(self.padding);
+        let mut text = layout::Node::new(Size::new(width, height));
+        text.move_to(Point::new(padding, padding));
+
+        let size = Size::new(width + 2.0 * padding, height + 2.0 * padding);
+
+        // Keep the tooltip inside of the window.
+        let x = position.x.min(bounds.width - size.width).max(0.0);
+        let y = position.y.min(bounds.height - size.height).max(0.0);
+
+        let mut node = layout::Node::with_children(size, vec![text]);
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        _event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        tab_bar::Renderer::draw_tooltip(
+            renderer,
+            defaults,
+            self.text,
+            layout,
+            self.text_size,
+            self.text_font,
+            self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut iced_native::Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.text.hash(state);
+        self.text_size.hash(state);
+        self.padding.hash(state);
+    }
+}
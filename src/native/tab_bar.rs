@@ -5,32 +5,55 @@
 //! 
 //! *This API requires the following crate features to be activated: tab_bar*
 use iced_native::Element;
-use std::hash::Hash;
+use std::{collections::HashSet, hash::Hash};
 
 use iced_native::{
     Align, Clipboard, Column, Event, Font, Hasher, Layout, Length, Point,
-    Rectangle, Row, Text, Widget, column, event, layout, mouse, row, text
+    Rectangle, Row, Size, Text, Widget, column, event, keyboard, layout, mouse,
+    overlay, row, text
 };
 
+use crate::native::SCROLL_LINE_HEIGHT;
+use crate::native::overlay::{TabBarMenuOverlay, TabBarTooltipOverlay};
+
+pub mod dock;
+pub use dock::{DockGroup, DropTarget, Edge, TabMove};
+use dock::DockDrag;
+
+pub mod orientation;
+pub use orientation::Orientation;
+
+pub mod tab;
+pub use tab::Tab;
+
+pub mod tab_badge;
+pub use tab_badge::TabBadge;
+
 pub mod tab_label;
 pub use tab_label::TabLabel;
 
+/// The distance the cursor has to be moved while pressing a tab before the
+/// tab is dragged.
+const DRAG_THRESHOLD: f32 = 5.0;
+
 /// A tab bar to show tabs.
 /// 
 /// # Example
 /// ```
-/// # use iced_aw::{TabLabel};
+/// # use iced_aw::{TabLabel, native::tab_bar};
 /// # use iced_native::{renderer::Null};
 /// #
-/// # pub type TabBar<Message> = iced_aw::native::TabBar<Message, Null>;
+/// # pub type TabBar<'a, Message> = iced_aw::native::TabBar<'a, Message, Null>;
 /// #[derive(Debug, Clone)]
 /// enum Message {
 ///     TabSelected(usize),
 /// }
 /// 
+/// let mut state = tab_bar::State::new();
 /// let active_tab = 0;
 /// 
 /// let tab_bar = TabBar::new(
+///     &mut state,
 ///     active_tab,
 ///     Message::TabSelected,
 /// )
@@ -39,13 +62,23 @@ pub use tab_label::TabLabel;
 /// .push(TabLabel::Text(String::from("Three")));
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct TabBar<Message, Renderer: self::Renderer> {
+pub struct TabBar<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
     active_tab: usize,
-    tab_labels: Vec<TabLabel>,
+    tabs: Vec<Tab<'a, Message, Renderer>>,
+    disabled_tabs: HashSet<usize>,
+    modified_tabs: HashSet<usize>,
+    pinned_tabs: HashSet<usize>,
     on_select: Box<dyn Fn(usize) -> Message>,
     on_close: Option<Box<dyn Fn(usize) -> Message>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+    on_new: Option<Box<dyn Fn() -> Message>>,
+    overflow_menu: bool,
+    dock: Option<Dock<'a, Message>>,
     width: Length,
     tab_width: Length,
+    min_tab_width: Option<u16>,
+    max_tab_width: Option<u16>,
     height: Length,
     max_height: u32,
     icon_size: u16,
@@ -55,25 +88,32 @@ pub struct TabBar<Message, Renderer: self::Renderer> {
     spacing: u16,
     icon_font: Option<Font>,
     text_font: Option<Font>,
+    orientation: Orientation,
+    cycle_shortcuts: bool,
+    number_shortcuts: bool,
+    close_shortcut: bool,
+    arrow_navigation: bool,
     style: Renderer::Style,
 }
 
-impl<Message, Renderer> TabBar<Message, Renderer>
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer>
 where
     Renderer: self::Renderer
 {
-    /// Creates a new [`TabBar`](TabBar) with the index of the selected tab and a
-    /// specified message which will be send when a tab is selected by the user.
-    pub fn new<F>(active_tab: usize, on_select: F) -> Self
+    /// Creates a new [`TabBar`](TabBar) with the given [`State`](State), the
+    /// index of the selected tab and a specified message which will be send
+    /// when a tab is selected by the user.
+    pub fn new<F>(state: &'a mut State, active_tab: usize, on_select: F) -> Self
     where
         F: 'static + Fn(usize) -> Message,
     {
-        Self::width_tab_labels(active_tab, Vec::new(), on_select)
+        Self::width_tab_labels(state, active_tab, Vec::new(), on_select)
     }
 
     /// Similar to `new` but with a given Vector of the
     /// [`TabLabel`](tab_label::TabLabel)s.Align
     pub fn width_tab_labels<F>(
+        state: &'a mut State,
         active_tab: usize,
         tab_labels: Vec<TabLabel>,
         on_select: F
@@ -82,12 +122,22 @@ where
         F: 'static + Fn(usize) -> Message,
     {
         TabBar {
+            state,
             active_tab,
-            tab_labels,
+            tabs: tab_labels.into_iter().map(Tab::Label).collect(),
+            disabled_tabs: HashSet::new(),
+            modified_tabs: HashSet::new(),
+            pinned_tabs: HashSet::new(),
             on_select: Box::new(on_select),
             on_close: None,
+            on_reorder: None,
+            on_new: None,
+            overflow_menu: false,
+            dock: None,
             width: Length::Fill,
             tab_width: Length::Fill,
+            min_tab_width: None,
+            max_tab_width: None,
             height: Length::Shrink,
             max_height: u32::MAX,
             icon_size: <Renderer as self::Renderer>::DEFAULT_ICON_SIZE,
@@ -97,15 +147,100 @@ where
             spacing: <Renderer as self::Renderer>::DEFAULT_SPACING,
             icon_font: None,
             text_font: None,
+            orientation: Orientation::Horizontal,
+            cycle_shortcuts: true,
+            number_shortcuts: true,
+            close_shortcut: true,
+            arrow_navigation: true,
             style: Renderer::Style::default(),
         }
     }
 
+    /// Gets the number of tabs on the [`TabBar`](TabBar).
+    pub fn tab_count(&self) -> usize {
+        self.tabs.len()
+    }
+
     /// Gets the index of the currently active tab on the [`TabBar`](TabBar).
     pub fn get_active_tab(&self) -> usize {
         self.active_tab
     }
 
+    /// Sets whether the tab at the given index of the [`TabBar`](TabBar) is
+    /// disabled.
+    /// 
+    /// A disabled tab can neither be selected nor closed by the user and is
+    /// skipped by the keyboard navigation.
+    pub fn tab_disabled(mut self, index: usize, disabled: bool) -> Self {
+        if disabled {
+            let _ = self.disabled_tabs.insert(index);
+        } else {
+            let _ = self.disabled_tabs.remove(&index);
+        }
+        self
+    }
+
+    /// Returns true if the tab at the given index of the [`TabBar`](TabBar)
+    /// is disabled.
+    pub fn is_tab_disabled(&self, index: usize) -> bool {
+        self.disabled_tabs.contains(&index)
+    }
+
+    /// Sets whether the tab at the given index of the [`TabBar`](TabBar) is
+    /// marked as modified.
+    /// 
+    /// A modified tab shows an indicator in place of its close icon until
+    /// the tab is hovered.
+    pub fn tab_modified(mut self, index: usize, modified: bool) -> Self {
+        if modified {
+            let _ = self.modified_tabs.insert(index);
+        } else {
+            let _ = self.modified_tabs.remove(&index);
+        }
+        self
+    }
+
+    /// Returns true if the tab at the given index of the [`TabBar`](TabBar)
+    /// is marked as modified.
+    pub fn is_tab_modified(&self, index: usize) -> bool {
+        self.modified_tabs.contains(&index)
+    }
+
+    /// Sets whether the tab at the given index of the [`TabBar`](TabBar) is
+    /// pinned.
+    /// 
+    /// A pinned tab only shows the icon of its label and is placed in front
+    /// of the unpinned tabs, where it keeps its position while the tabs are
+    /// scrolled. It can neither be closed nor dragged by the user.
+    pub fn tab_pinned(mut self, index: usize, pinned: bool) -> Self {
+        if pinned {
+            let _ = self.pinned_tabs.insert(index);
+        } else {
+            let _ = self.pinned_tabs.remove(&index);
+        }
+        self
+    }
+
+    /// Returns true if the tab at the given index of the [`TabBar`](TabBar)
+    /// is pinned.
+    pub fn is_tab_pinned(&self, index: usize) -> bool {
+        self.pinned_tabs.contains(&index)
+    }
+
+    /// Returns the [`TabStatus`](TabStatus) of the tab at the given index of
+    /// the [`TabBar`](TabBar).
+    fn tab_status(&self, index: usize) -> TabStatus {
+        let is_disabled = self.is_tab_disabled(index);
+        let is_pinned = self.is_tab_pinned(index);
+
+        TabStatus {
+            is_disabled,
+            is_modified: self.is_tab_modified(index),
+            is_closable: self.on_close.is_some() && !is_disabled && !is_pinned,
+            is_pinned,
+        }
+    }
+
     /// Sets the message that will be produced when the close icon of a tab
     /// on the [`TabBar`](TabBar) is pressed.
     /// 
@@ -118,6 +253,76 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab of the
+    /// [`TabBar`](TabBar) is dragged to a new position.
+    /// 
+    /// The message is created from the index the tab was dragged from and
+    /// the index the tab should have after removing it from its old position.
+    /// 
+    /// Setting this enables the reordering of the tabs by drag and drop.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the message that will be produced when the button for creating
+    /// a new tab on the [`TabBar`](TabBar) is pressed.
+    /// 
+    /// Setting this enables the drawing of the button behind the tabs.
+    pub fn on_new(mut self, on_new: Message) -> Self
+    where
+        Message: 'static + Clone,
+    {
+        self.on_new = Some(Box::new(move || on_new.clone()));
+        self
+    }
+
+    /// Sets whether the [`TabBar`](TabBar) shows a button opening a menu
+    /// that lists all tabs, including those scrolled out of view.
+    pub fn overflow_menu(mut self, enabled: bool) -> Self {
+        self.overflow_menu = enabled;
+        self
+    }
+
+    /// Adds the [`TabBar`](TabBar) to the given [`DockGroup`](DockGroup)
+    /// using the given identifier, which has to be unique within the group.
+    /// 
+    /// The tabs can then be dragged to the other [`TabBar`](TabBar)s of the
+    /// group. The message is produced by the [`TabBar`](TabBar) a tab is
+    /// dropped on and describes the [`TabMove`](TabMove).
+    pub fn dock<F>(mut self, group: &'a DockGroup, id: usize, on_move: F) -> Self
+    where
+        F: 'static + Fn(TabMove) -> Message,
+    {
+        self.dock = Some(Dock {
+            group,
+            id,
+            on_move: Box::new(on_move),
+        });
+        self
+    }
+
+    /// Returns the [`DockGroup`](DockGroup) of the [`TabBar`](TabBar) along
+    /// with its identifier within the group.
+    pub(crate) fn get_dock(&self) -> Option<(&'a DockGroup, usize)> {
+        self.dock.as_ref().map(|dock| (dock.group, dock.id))
+    }
+
+    /// Returns the message for moving the tab of the given
+    /// [`DockDrag`](DockDrag) to the given [`DropTarget`](DropTarget) of the
+    /// [`TabBar`](TabBar).
+    pub(crate) fn move_message(&self, drag: DockDrag, drop: DropTarget) -> Option<Message> {
+        self.dock.as_ref().map(|dock| (dock.on_move)(TabMove {
+            source: drag.source,
+            index: drag.index,
+            target: dock.id,
+            drop,
+        }))
+    }
+
     /// Sets the width of the [`TabBar`](TabBar).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -130,11 +335,35 @@ where
     }
 
     /// Sets the width of a tab on the [`TabBar`](TabBar).
+    /// 
+    /// On a horizontal [`TabBar`](TabBar), [`Length::Shrink`](Length::Shrink)
+    /// fits each tab to its content, while [`Length::Fill`](Length::Fill)
+    /// gives all tabs the same width. The tabs shrink down to the
+    /// [`min_tab_width`](TabBar::min_tab_width) before they start to
+    /// overflow. Labels that don't fit into their tab are truncated with an
+    /// ellipsis and show their full text when hovered.
     pub fn tab_width(mut self, width: Length) -> Self {
         self.tab_width = width;
         self
     }
 
+    /// Sets the minimum width of each tab on a horizontal
+    /// [`TabBar`](TabBar).
+    /// 
+    /// If it is not set, tabs of the width [`Length::Fill`](Length::Fill)
+    /// don't shrink below the width of their content.
+    pub fn min_tab_width(mut self, min_tab_width: u16) -> Self {
+        self.min_tab_width = Some(min_tab_width);
+        self
+    }
+
+    /// Sets the maximum width of each tab on a horizontal
+    /// [`TabBar`](TabBar).
+    pub fn max_tab_width(mut self, max_tab_width: u16) -> Self {
+        self.max_tab_width = Some(max_tab_width);
+        self
+    }
+
     /// Sets the height of the [`TabBar`](TabBar).
     pub fn height(mut self, height: Length) -> Self {
         self.height = height;
@@ -199,82 +428,149 @@ where
         self
     }
 
+    /// Sets the [`Orientation`](Orientation) of the [`TabBar`](TabBar).
+    /// 
+    /// A vertical [`TabBar`](TabBar) places its tabs below each other.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets whether the tabs of the [`TabBar`](TabBar) can be cycled with
+    /// `Ctrl+Tab` and `Ctrl+Shift+Tab`.
+    /// 
+    /// This is enabled by default.
+    pub fn cycle_shortcuts(mut self, enabled: bool) -> Self {
+        self.cycle_shortcuts = enabled;
+        self
+    }
+
+    /// Sets whether the first eight tabs of the [`TabBar`](TabBar) can be
+    /// selected with `Ctrl+1` to `Ctrl+8` and the last tab with `Ctrl+9`.
+    /// 
+    /// This is enabled by default.
+    pub fn number_shortcuts(mut self, enabled: bool) -> Self {
+        self.number_shortcuts = enabled;
+        self
+    }
+
+    /// Sets whether the active tab of the [`TabBar`](TabBar) can be closed
+    /// with `Ctrl+W`. This only has an effect if
+    /// [`on_close`](TabBar::on_close) is set.
+    /// 
+    /// This is enabled by default.
+    pub fn close_shortcut(mut self, enabled: bool) -> Self {
+        self.close_shortcut = enabled;
+        self
+    }
+
+    /// Sets whether the arrow keys select the previous or next tab while the
+    /// [`TabBar`](TabBar) is focused. The [`TabBar`](TabBar) gets focused by
+    /// clicking on it.
+    /// 
+    /// This is enabled by default.
+    pub fn arrow_navigation(mut self, enabled: bool) -> Self {
+        self.arrow_navigation = enabled;
+        self
+    }
+
+    /// Sets whether the [`TabBar`](TabBar) is focused and reacts to the
+    /// arrow keys.
+    /// 
+    /// This allows a wrapping widget to extend the area focusing the
+    /// [`TabBar`](TabBar) when clicked.
+    pub(crate) fn set_focused(&mut self, is_focused: bool) {
+        self.state.is_focused = is_focused;
+    }
+
+    /// Returns true if the menu listing all tabs of the [`TabBar`](TabBar)
+    /// is open.
+    pub(crate) fn is_menu_open(&self) -> bool {
+        self.overflow_menu && self.state.menu_open
+    }
+
     /// Sets the style of the [`TabBar`](TabBar).
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
         self
     }
 
+    /// Gets the style of the [`TabBar`](TabBar).
+    pub fn get_style(&self) -> &Renderer::Style {
+        &self.style
+    }
+
     /// Pushes a [`TabLabel`](tab_label::TabLabel) to the [`TabBar`](TabBar).
-    pub fn push<'a>(mut self, tab_label: TabLabel) -> Self {
-        self.tab_labels.push(tab_label);
+    pub fn push(mut self, tab_label: TabLabel) -> Self {
+        self.tabs.push(Tab::Label(tab_label));
+        self
+    }
+
+    /// Pushes an arbitrary [`Element`](iced_native::Element) as the label of
+    /// a tab to the [`TabBar`](TabBar).
+    pub fn push_element<E>(mut self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.tabs.push(Tab::Element(element.into()));
         self
     }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for TabBar<Message, Renderer>
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer>
 where
     Renderer: self::Renderer + column::Renderer + text::Renderer + row::Renderer,
 {
-    fn width(&self) -> Length {
-        self.width
-    }
-
-    fn height(&self) -> Length {
-        self.height
-    }
-
-    fn layout(
+    /// Lays out the tabs at the given indices of the [`TabBar`](TabBar) with
+    /// the given widths of the tabs and the given length of the
+    /// [`TabBar`](TabBar) along its [`Orientation`](Orientation).
+    fn tabs_node(
         &self,
         renderer: &Renderer,
         limits: &layout::Limits,
+        indices: &[usize],
+        tab_widths: &[Length],
+        length: Length,
     ) -> layout::Node {
-        self.tab_labels.iter()
-            .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
-                let label = match tab_label {
-                        TabLabel::Icon(_icon) => {
-                            Column::new()
-                                .align_items(Align::Center)
-                                .push(
-                                    Row::new()
-                                        .width(Length::Units(self.icon_size))
-                                        .height(Length::Units(self.icon_size))
-                                )
-                        },
-                        TabLabel::Text(text) => {
-                            Column::new()
-                                .align_items(Align::Center)
-                                .push(
-                                    Text::new(text)
-                                        .size(self.text_size)
-                                        .width(self.tab_width)
-                                )
-                        },
-                        TabLabel::IconText(_icon, text) => {
-                            Column::new()
-                                .align_items(Align::Center)
-                                .push(
-                                    Row::new()
-                                        .width(Length::Units(self.icon_size))
-                                        .height(Length::Units(self.icon_size))
-                                )
-                                .push(
-                                    Text::new(text)
-                                        .size(self.text_size)
-                                        .width(self.tab_width)
-                                )
-                        },
-                    }
-                    .width(self.tab_width)
-                    .height(self.height);
+        let label_height = match self.orientation {
+            Orientation::Horizontal => self.height,
+            Orientation::Vertical => Length::Shrink,
+        };
+
+        let tabs: Vec<Element<'_, Message, Renderer>> = indices.iter()
+            .map(|&index| (index, &self.tabs[index]))
+            .zip(tab_widths)
+            .map(|((index, tab), &tab_width)| {
+                let is_pinned = self.is_tab_pinned(index);
+
+                // A label fills the remaining space of a tab with a fixed width.
+                let label_width = match tab_width {
+                    Length::Units(_) => Length::Fill,
+                    _ => tab_width,
+                };
+
+                let label = match tab {
+                    Tab::Label(tab_label) if is_pinned => {
+                        self.label_column(&tab_label.pinned_label(), label_width)
+                    },
+                    Tab::Label(tab_label) => self.label_column(tab_label, label_width),
+                    Tab::Element(element) => {
+                        Column::new()
+                            .align_items(Align::Center)
+                            .push(Element::new(ElementLabel { element }))
+                    },
+                }
+                .width(label_width)
+                .height(label_height);
 
                 let mut label_row = Row::new()
                     .align_items(Align::Center)
                     .padding(self.padding)
-                    .width(self.tab_width)
+                    .width(tab_width)
                     .push(label);
 
-                if self.on_close.is_some() {
+                // Pinned tabs can't be closed.
+                if (self.on_close.is_some() && !is_pinned) || self.is_tab_modified(index) {
                     label_row = label_row
                         .push(
                             Row::new()
@@ -284,162 +580,1545 @@ where
                         );
                 }
 
-                row.push(label_row)
+                label_row.into()
             })
-            .width(self.width)
-            .height(self.height)
-            .spacing(self.spacing)
-            .layout(renderer, limits)
+            .collect();
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                Row::with_children(tabs)
+                    .width(length)
+                    .height(self.height)
+                    .spacing(self.spacing)
+                    .layout(renderer, limits)
+            },
+            Orientation::Vertical => {
+                Column::with_children(tabs)
+                    .width(self.width)
+                    .height(length)
+                    .spacing(self.spacing)
+                    .layout(renderer, limits)
+            },
+        }
     }
 
-    fn on_event(
-        &mut self,
-        event: Event,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        messages: &mut Vec<Message>,
-        _renderer: &Renderer,
-        _clipboard: Option<&dyn Clipboard>
-    ) -> event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if layout.bounds().contains(cursor_position) {
-                    let tabs_map: Vec<bool> = layout.children()
-                        .map(|layout| layout.bounds().contains(cursor_position))
-                        .collect();
-
-                    if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
-                        messages.push(
-                            self.on_close.as_ref().filter(|_on_close| {
-                                let tab_layout = layout.children().nth(new_selected).unwrap();
-                                let cross_layout = tab_layout.children().nth(1).unwrap();
-    
-                                cross_layout.bounds().contains(cursor_position)
-                            })
-                            .map(|on_close| (on_close)(new_selected))
-                            .unwrap_or((self.on_select)(new_selected))
-                        );
-                        return event::Status::Captured;
-                    }
-                }
+    /// Returns the [`Column`](iced_native::Column) used to lay out the given
+    /// [`TabLabel`](TabLabel).
+    fn label_column<'b>(&self, tab_label: &TabLabel, tab_width: Length)
+        -> Column<'b, Message, Renderer>
+    {
+        match tab_label {
+            TabLabel::Icon(_icon) => {
+                Column::new()
+                    .align_items(Align::Center)
+                    .push(
+                        Row::new()
+                            .width(Length::Units(self.icon_size))
+                            .height(Length::Units(self.icon_size))
+                    )
             },
-            _ => {}
-        };
+            TabLabel::Text(text) => {
+                Column::new()
+                    .align_items(Align::Center)
+                    .push(
+                        Text::new(text)
+                            .size(self.text_size)
+                            .width(tab_width)
+                            .height(Length::Units(self.text_size))
+                    )
+            },
+            TabLabel::IconText(_icon, text) => {
+                Column::new()
+                    .align_items(Align::Center)
+                    .push(
+                        Row::new()
+                            .width(Length::Units(self.icon_size))
+                            .height(Length::Units(self.icon_size))
+                    )
+                    .push(
+                        Text::new(text)
+                            .size(self.text_size)
+                            .width(tab_width)
+                            .height(Length::Units(self.text_size))
+                    )
+            },
+            TabLabel::WithBadge(label, _badge) => self.label_column(label, tab_width),
+        }
+    }
 
-        event::Status::Ignored
+    /// Returns the widths of the tabs of a horizontal [`TabBar`](TabBar)
+    /// with the given widths of their content if they fit into the given
+    /// available width and if they are overflowing.
+    fn tab_widths(&self, content_widths: &[f32], available: f32)
+        -> (Vec<Length>, Vec<Length>)
+    {
+        let min = self.min_tab_width.map_or(0.0, f32::from);
+        let max = self.max_tab_width.map_or(f32::INFINITY, f32::from);
+        let clamp = |width: f32| Length::Units(width.min(max).max(min) as u16);
+
+        let spacing = f32::from(self.spacing)
+            * content_widths.len().saturating_sub(1) as f32;
+        let share = (available - spacing) / content_widths.len() as f32;
+
+        content_widths.iter()
+            .map(|&content_width| match self.tab_width {
+                Length::Units(width) => (clamp(f32::from(width)), clamp(f32::from(width))),
+                Length::Shrink => (clamp(content_width), clamp(content_width)),
+                Length::Fill | Length::FillPortion(_) => (
+                    clamp(if share.is_finite() { share } else { content_width }),
+                    clamp(if self.min_tab_width.is_some() { min } else { content_width }),
+                ),
+            })
+            .unzip()
     }
 
-    fn draw(
-        &self,
-        renderer: &mut Renderer,
-        defaults: &Renderer::Defaults,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        _viewport: &Rectangle,
-    ) -> Renderer::Output {
-        self::Renderer::draw(
-            renderer,
-            defaults,
-            self.active_tab,
-            &self.tab_labels,
-            layout,
-            cursor_position,
-            self.icon_font,
-            self.text_font,
-            &self.style,
-        )
+    /// Returns the number of buttons behind the tabs of the
+    /// [`TabBar`](TabBar).
+    fn button_count(&self) -> usize {
+        usize::from(self.on_new.is_some()) + usize::from(self.overflow_menu)
     }
 
-    fn hash_layout(&self, state: &mut Hasher) {
-        struct Marker;
-        std::any::TypeId::of::<Marker>().hash(state);
+    /// Returns the bounds of the button for creating a new tab and of the
+    /// button opening the menu of the [`TabBar`](TabBar) if they are shown.
+    fn button_bounds(&self, layout: Layout<'_>) -> (Option<Rectangle>, Option<Rectangle>) {
+        let mut buttons = layout.children()
+            .skip(self.tabs.len())
+            .map(|button_layout| button_layout.bounds());
 
-        self.active_tab.hash(state);
-        self.tab_labels.iter().for_each(|tab| tab.hash(state));
-        self.width.hash(state);
-        self.height.hash(state);
-        self.max_height.hash(state);
-        self.icon_size.hash(state);
-        self.text_size.hash(state);
-        self.close_size.hash(state);
+        let new_button = buttons.by_ref().take(usize::from(self.on_new.is_some())).next();
+        let menu_button = buttons.take(usize::from(self.overflow_menu)).next();
+
+        (new_button, menu_button)
     }
-}
 
+    /// Returns the bounds of the arrows for scrolling the tabs of the
+    /// [`TabBar`](TabBar) if its tabs are overflowing.
+    fn arrow_bounds(&self, layout: Layout<'_>) -> Option<(Rectangle, Rectangle)> {
+        let mut arrows = layout.children()
+            .skip(self.tabs.len() + self.button_count())
+            .map(|arrow_layout| arrow_layout.bounds());
 
-/// The renderer of a [`TabBar`](TabBar).
-/// 
-/// Your renderer will need to implement this trait before being
-/// able to use a [`TabBar`](TabBar) in your user interface.
-pub trait Renderer: iced_native::Renderer {
+        arrows.next().zip(arrows.next())
+    }
 
-    /// The style supported by this renderer.
-    type Style: Default;
+    /// Returns the bounds of the visible area of the tabs between the
+    /// scroll arrows if the tabs of the [`TabBar`](TabBar) are overflowing.
+    fn strip_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.arrow_bounds(layout).map(|(back, forward)| {
+            match self.orientation {
+                Orientation::Horizontal => Rectangle {
+                    x: back.x + back.width,
+                    width: forward.x - back.x - back.width,
+                    .. layout.bounds()
+                },
+                Orientation::Vertical => Rectangle {
+                    y: back.y + back.height,
+                    height: forward.y - back.y - back.height,
+                    .. layout.bounds()
+                },
+            }
+        })
+    }
 
-    /// The default icon size of a [`TabBar`](TabBar).
-    const DEFAULT_ICON_SIZE: u16;
+    /// Returns the maximum scroll offset of the tabs of the
+    /// [`TabBar`](TabBar).
+    fn max_scroll_offset(&self, layout: Layout<'_>) -> f32 {
+        let strip = match self.strip_bounds(layout) {
+            Some(strip) => strip,
+            None => return 0.0,
+        };
 
-    /// The default text size of a [`TabBar`](TabBar).
-    const DEFAULT_TEXT_SIZE: u16;
+        let (strip_start, strip_length) = main_axis(self.orientation, strip);
 
-    /// The default close size of a [`TabBar`](TabBar).
-    const DEFAULT_CLOSE_SIZE: u16;
+        layout.children()
+            .take(self.tabs.len())
+            .enumerate()
+            .filter(|(index, _)| !self.is_tab_pinned(*index))
+            .map(|(_, tab_layout)| {
+                let (start, length) = main_axis(self.orientation, tab_layout.bounds());
 
-    /// The default padding of a [`TabBar`](TabBar).
-    const DEFAULT_PADDING: u16;
+                (start + length - strip_start - strip_length).max(0.0)
+            })
+            .fold(0.0, f32::max)
+    }
 
-    /// The default spacing of a [`TabBar`](TabBar).
-    const DEFAULT_SPACING: u16;
+    /// Returns the current scroll offset of the tabs of the
+    /// [`TabBar`](TabBar).
+    /// 
+    /// If the active tab changed since the last event, the offset is
+    /// adjusted so that the active tab becomes visible.
+    fn scroll_offset(&self, layout: Layout<'_>) -> f32 {
+        let strip = match self.strip_bounds(layout) {
+            Some(strip) => strip,
+            None => return 0.0,
+        };
 
-    /// Draws a [`TabBar`](TabBar).
-    fn draw(
-        &mut self,
-        defaults: &Self::Defaults,
-        active_tab: usize,
-        tab_labels: &[TabLabel],
-        layout: Layout<'_>,
-        cursor_position: Point,
-        icon_font: Option<Font>,
-        text_font: Option<Font>,
-        style_sheet: &Self::Style,
-    ) -> Self::Output;
-}
+        let mut offset = self.state.scroll_offset;
 
-#[cfg(debug_assertions)]
-impl Renderer for iced_native::renderer::Null {
-    type Style = ();
+        // Pinned tabs are always visible.
+        if self.state.scrolled_to != Some(self.active_tab)
+            && !self.is_tab_pinned(self.active_tab)
+        {
+            if let Some(tab_layout) = layout.children().take(self.tabs.len()).nth(self.active_tab) {
+                let (strip_start, strip_length) = main_axis(self.orientation, strip);
+                let (start, length) = main_axis(self.orientation, tab_layout.bounds());
+                let start = start - strip_start;
 
-    const DEFAULT_ICON_SIZE: u16 = 0;
+                if start < offset {
+                    offset = start;
+                } else if start + length > offset + strip_length {
+                    offset = start + length - strip_length;
+                }
+            }
+        }
 
-    const DEFAULT_TEXT_SIZE: u16 = 0;
+        offset.max(0.0).min(self.max_scroll_offset(layout))
+    }
 
-    const DEFAULT_CLOSE_SIZE: u16 = 0;
+    /// Returns the position of the cursor relative to the scrolled tabs of
+    /// the [`TabBar`](TabBar).
+    fn tabs_cursor_position(&self, layout: Layout<'_>, cursor_position: Point) -> Point {
+        tabs_cursor_position(
+            self.strip_bounds(layout),
+            self.scroll_offset(layout),
+            self.orientation,
+            cursor_position,
+        )
+    }
 
-    const DEFAULT_PADDING: u16 = 0;
+    /// Returns the index of the tab in front of which a dragged tab would be
+    /// dropped at the given cursor position.
+    /// 
+    /// Tabs can't be dropped in between the pinned tabs.
+    fn drop_index(&self, layout: Layout<'_>, cursor_position: Point) -> usize {
+        let (cursor, _) = main_axis(
+            self.orientation,
+            Rectangle::new(cursor_position, Size::ZERO),
+        );
+        let tab_bounds: Vec<Rectangle> = layout.children()
+            .take(self.tabs.len())
+            .map(|tab_layout| tab_layout.bounds())
+            .collect();
 
-    const DEFAULT_SPACING: u16 = 0;
+        self.drop_index_in(&tab_bounds, cursor + self.scroll_offset(layout))
+    }
 
-    fn draw(
-        &mut self,
-        _defaults: &Self::Defaults,
-        _active_tab: usize,
-        _tab_labels: &[TabLabel],
-        _layout: Layout<'_>,
-        _cursor_position: Point,
-        _icon_font: Option<Font>,
-        _text_font: Option<Font>,
-        _style_sheet: &Self::Style,
-    ) -> Self::Output {}
-}
+    /// Returns the index of the tab in front of which a dragged tab would be
+    /// dropped at the given position along the axis of the tabs with the
+    /// given bounds.
+    fn drop_index_in(&self, tab_bounds: &[Rectangle], cursor: f32) -> usize {
+        // The tabs are compared in the order they are displayed.
+        self.display_order()
+            .into_iter()
+            .filter(|index| !self.is_tab_pinned(*index))
+            .find(|index| {
+                let (start, length) = main_axis(self.orientation, tab_bounds[*index]);
 
+                start + length / 2.0 >= cursor
+            })
+            .unwrap_or_else(|| self.tabs.len())
+    }
 
-impl<'a, Message, Renderer> From<TabBar<Message, Renderer>>
-    for Element<'a, Message, Renderer>
-where
-    Renderer: 'a + self::Renderer + column::Renderer + text::Renderer
-        + row::Renderer,
+    /// Returns true if the text of the tab at the given index is truncated or
+    /// hidden by pinning the tab.
+    fn is_text_truncated(&self, renderer: &Renderer, layout: Layout<'_>, index: usize) -> bool {
+        let tab_label = match self.tabs.get(index) {
+            Some(Tab::Label(tab_label)) => tab_label,
+            _ => return false,
+        };
+        let text = match tab_label.get_text() {
+            Some(text) => text,
+            None => return false,
+        };
+
+        if self.is_tab_pinned(index) && tab_label.pinned_label().get_text().is_none() {
+            return true;
+        }
+
+        // The text is the last child of the label.
+        let label_width = layout.children()
+            .nth(index)
+            .and_then(|tab_layout| tab_layout.children().next())
+            .and_then(|label_layout| label_layout.children().last())
+            .map_or(0.0, |text_layout| text_layout.bounds().width);
+
+        let (width, _) = renderer.measure(
+            text,
+            self.text_size,
+            self.text_font.unwrap_or_default(),
+            Size::INFINITY,
+        );
+
+        width > label_width.ceil()
+    }
+
+    /// Returns the tooltip showing the full text of the hovered tab of the
+    /// [`TabBar`](TabBar) in case its label is truncated.
+    fn tooltip(&self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.state.drag.map_or(false, |drag| drag.is_dragging) {
+            return None;
+        }
+
+        // Only tabs with a truncated text are kept as hovered.
+        let (index, cursor_position) = self.state.hovered_tab?;
+        let text = match self.tabs.get(index)? {
+            Tab::Label(tab_label) => tab_label.get_text()?,
+            Tab::Element(_) => return None,
+        };
+
+        let bounds = layout.bounds();
+        let position = match self.orientation {
+            Orientation::Horizontal => Point::new(
+                cursor_position.x,
+                bounds.y + bounds.height,
+            ),
+            Orientation::Vertical => Point::new(
+                bounds.x + bounds.width,
+                cursor_position.y,
+            ),
+        };
+
+        Some(
+            TabBarTooltipOverlay::new(
+                text,
+                self.text_size,
+                self.padding,
+                self.text_font,
+                &self.style,
+            )
+            .overlay(position)
+        )
+    }
+
+    /// Returns the currently dragged tab of the [`TabBar`](TabBar).
+    fn dragged_tab(&self, layout: Layout<'_>) -> Option<DraggedTab> {
+        let drag = self.state.drag.filter(|drag| drag.is_dragging)?;
+
+        // The tab is dragged to another TabBar of the dock group.
+        if self.dock.is_some() && !layout.bounds().contains(drag.position) {
+            return None;
+        }
+
+        let offset = match self.orientation {
+            Orientation::Horizontal => drag.position.x - drag.origin.x,
+            Orientation::Vertical => drag.position.y - drag.origin.y,
+        };
+
+        Some(DraggedTab {
+            index: drag.index,
+            drop_index: self.drop_index(layout, drag.position),
+            offset,
+        })
+    }
+
+    /// Handles the dragging of the tabs of the [`TabBar`](TabBar).
+    /// 
+    /// Returns the status if the event was processed.
+    fn on_drag_event(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) -> Option<event::Status> {
+        let mut drag = self.state.drag?;
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let delta = cursor_position - drag.origin;
+
+                drag.position = cursor_position;
+                drag.is_dragging = drag.is_dragging
+                    || (delta.x * delta.x + delta.y * delta.y).sqrt() > DRAG_THRESHOLD;
+                self.state.drag = Some(drag);
+
+                if let (true, Some(dock)) = (drag.is_dragging, &self.dock) {
+                    dock.group.set_drag(if layout.bounds().contains(cursor_position) {
+                        None
+                    } else {
+                        Some(DockDrag {
+                            source: dock.id,
+                            index: drag.index,
+                            position: cursor_position,
+                            is_released: false,
+                        })
+                    });
+                }
+
+                if drag.is_dragging {
+                    Some(event::Status::Captured)
+                } else {
+                    None
+                }
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.drag = None;
+
+                if !drag.is_dragging {
+                    return None;
+                }
+
+                if let Some(dock) = &self.dock {
+                    if !layout.bounds().contains(cursor_position) {
+                        // Leave the tab to the widget it is dropped on.
+                        dock.group.set_drag(dock.group.drag().map(|dock_drag| DockDrag {
+                            position: cursor_position,
+                            is_released: true,
+                            .. dock_drag
+                        }));
+
+                        return Some(event::Status::Captured);
+                    }
+
+                    dock.group.set_drag(None);
+                }
+
+                let drop_index = self.drop_index(layout, cursor_position);
+                let new_index = if drop_index > drag.index {
+                    drop_index - 1
+                } else {
+                    drop_index
+                };
+
+                if let Some(on_reorder) = &self.on_reorder {
+                    if new_index != drag.index {
+                        messages.push((on_reorder)(drag.index, new_index));
+                    }
+                }
+
+                Some(event::Status::Captured)
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the index at which a tab dragged from another
+    /// [`TabBar`](TabBar) of the [`DockGroup`](DockGroup) would be dropped.
+    fn dock_drop_index(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        let dock = self.dock.as_ref()?;
+        let drag = dock.group.drag()?;
+
+        if drag.is_released
+            || drag.source == dock.id
+            || !layout.bounds().contains(cursor_position)
+        {
+            return None;
+        }
+
+        Some(self.drop_index(layout, cursor_position))
+    }
+
+    /// Handles the dropping of tabs dragged from another [`TabBar`](TabBar)
+    /// of the [`DockGroup`](DockGroup).
+    /// 
+    /// Returns the status if the event was processed.
+    fn on_dock_event(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) -> Option<event::Status> {
+        let dock = self.dock.as_ref()?;
+        let drag = dock.group.drag()?;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if drag.source == dock.id || !layout.bounds().contains(cursor_position) {
+                    return None;
+                }
+
+                let drop_index = self.drop_index(layout, cursor_position);
+                let drag = dock.group.take_drag()?;
+                messages.extend(self.move_message(drag, DropTarget::Index(drop_index)));
+
+                Some(event::Status::Captured)
+            },
+            _ => {
+                // Nothing accepted the released tab.
+                if drag.is_released {
+                    dock.group.set_drag(None);
+                }
+
+                None
+            },
+        }
+    }
+
+    /// Returns the indices of the tabs in the order they are displayed, with
+    /// the pinned tabs in front of the others.
+    fn display_order(&self) -> Vec<usize> {
+        let (pinned, unpinned): (Vec<usize>, Vec<usize>) = (0..self.tabs.len())
+            .partition(|index| self.is_tab_pinned(*index));
+
+        pinned.into_iter().chain(unpinned).collect()
+    }
+
+    /// Returns the index of the enabled tab displayed next to the given tab
+    /// in the given direction, wrapping around at the ends if `wrap` is true.
+    fn next_tab(&self, from: usize, forward: bool, wrap: bool) -> Option<usize> {
+        let order = self.display_order();
+        let len = order.len();
+        let from = order.iter().position(|index| *index == from).unwrap_or(len);
+
+        let mut candidates: Box<dyn Iterator<Item = usize>> = match (forward, wrap) {
+            (true, false) => Box::new(from + 1..len),
+            (true, true) => Box::new((from + 1..len).chain(0..from)),
+            (false, false) => Box::new((0..from).rev()),
+            (false, true) => Box::new((0..from).rev().chain((from + 1..len).rev())),
+        };
+
+        candidates
+            .map(|position| order[position])
+            .find(|index| !self.is_tab_disabled(*index))
+    }
+
+    /// Handles the keyboard navigation of the [`TabBar`](TabBar).
+    /// 
+    /// Only a focused [`TabBar`](TabBar) reacts to the arrow keys, so they
+    /// can still be used by other widgets in the same window.
+    /// 
+    /// Returns the status if the event was processed.
+    fn on_keyboard_event(
+        &self,
+        event: &Event,
+        messages: &mut Vec<Message>,
+    ) -> Option<event::Status> {
+        let (key_code, modifiers) = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, modifiers }) => {
+                (*key_code, *modifiers)
+            },
+            _ => return None,
+        };
+
+        let tab = if modifiers.control {
+            match key_code {
+                keyboard::KeyCode::Tab if self.cycle_shortcuts => {
+                    self.next_tab(self.active_tab, !modifiers.shift, true)
+                },
+                keyboard::KeyCode::W if self.close_shortcut => {
+                    let on_close = self.on_close.as_ref().filter(|_| {
+                        self.active_tab < self.tabs.len()
+                            && !self.is_tab_disabled(self.active_tab)
+                            && !self.is_tab_pinned(self.active_tab)
+                    })?;
+                    messages.push((on_close)(self.active_tab));
+
+                    return Some(event::Status::Captured);
+                },
+                _ if self.number_shortcuts => {
+                    let number = match key_code {
+                        keyboard::KeyCode::Key1 => 1,
+                        keyboard::KeyCode::Key2 => 2,
+                        keyboard::KeyCode::Key3 => 3,
+                        keyboard::KeyCode::Key4 => 4,
+                        keyboard::KeyCode::Key5 => 5,
+                        keyboard::KeyCode::Key6 => 6,
+                        keyboard::KeyCode::Key7 => 7,
+                        keyboard::KeyCode::Key8 => 8,
+                        keyboard::KeyCode::Key9 => 9,
+                        _ => return None,
+                    };
+
+                    let order = self.display_order();
+
+                    if number == 9 {
+                        order.last().copied()
+                    } else {
+                        order.get(number - 1).copied()
+                    }
+                    .filter(|index| !self.is_tab_disabled(*index))
+                },
+                _ => return None,
+            }
+        } else if self.arrow_navigation
+            && self.state.is_focused
+            && !(modifiers.shift || modifiers.alt || modifiers.logo)
+        {
+            match (self.orientation, key_code) {
+                (Orientation::Horizontal, keyboard::KeyCode::Left)
+                | (Orientation::Vertical, keyboard::KeyCode::Up) => {
+                    self.next_tab(self.active_tab, false, false)
+                },
+                (Orientation::Horizontal, keyboard::KeyCode::Right)
+                | (Orientation::Vertical, keyboard::KeyCode::Down) => {
+                    self.next_tab(self.active_tab, true, false)
+                },
+                _ => return None,
+            }
+        } else {
+            return None;
+        };
+
+        if let Some(tab) = tab {
+            messages.push((self.on_select)(tab));
+        }
+
+        Some(event::Status::Captured)
+    }
+
+    /// Handles the scrolling of the overflowing tabs of the
+    /// [`TabBar`](TabBar) by the scroll arrows and the mouse wheel.
+    /// 
+    /// Returns the status if the event was processed.
+    fn on_scroll_event(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<event::Status> {
+        let (back, forward) = self.arrow_bounds(layout)?;
+        let strip = self.strip_bounds(layout)?;
+
+        if !layout.bounds().contains(cursor_position) {
+            return None;
+        }
+
+        let (_, strip_length) = main_axis(self.orientation, strip);
+
+        let delta = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if back.contains(cursor_position) {
+                    -strip_length / 2.0
+                } else if forward.contains(cursor_position) {
+                    strip_length / 2.0
+                } else {
+                    return None;
+                }
+            },
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        -(if *x != 0.0 { *x } else { *y }) * SCROLL_LINE_HEIGHT
+                    },
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        -(if *x != 0.0 { *x } else { *y })
+                    },
+                }
+            },
+            _ => return None,
+        };
+
+        self.state.scroll_offset = (self.state.scroll_offset + delta)
+            .max(0.0)
+            .min(self.max_scroll_offset(layout));
+
+        Some(event::Status::Captured)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TabBar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + column::Renderer + text::Renderer + row::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let button_length = f32::from(self.close_size + 2 * self.padding);
+        let buttons_length = self.button_count() as f32 * button_length;
+
+        let (pinned, unpinned): (Vec<usize>, Vec<usize>) = (0..self.tabs.len())
+            .partition(|index| self.is_tab_pinned(*index));
+
+        // The pinned tabs are placed in front of the scrollable tabs.
+        let pinned_width = match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.tab_width,
+        };
+        let pinned_node = self.tabs_node(
+            renderer,
+            limits,
+            &pinned,
+            &vec![pinned_width; pinned.len()],
+            Length::Shrink,
+        );
+        let pinned_length = match (pinned.is_empty(), unpinned.is_empty()) {
+            (true, _) => 0.0,
+            (false, true) => main_axis(self.orientation, pinned_node.bounds()).1,
+            (false, false) => {
+                main_axis(self.orientation, pinned_node.bounds()).1 + f32::from(self.spacing)
+            },
+        };
+
+        // The buttons are placed behind the tabs.
+        let tabs_limits = match self.orientation {
+            Orientation::Horizontal => {
+                limits.shrink(Size::new(pinned_length + buttons_length, 0.0))
+            },
+            Orientation::Vertical => {
+                limits.shrink(Size::new(0.0, pinned_length + buttons_length))
+            },
+        };
+
+        let (node, content) = match self.orientation {
+            Orientation::Horizontal => {
+                let unbounded = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(f32::INFINITY, limits.max().height),
+                );
+
+                // Lay out the tabs by their content to get their natural widths.
+                let natural = self.tabs_node(
+                    renderer,
+                    &unbounded,
+                    &unpinned,
+                    &vec![Length::Shrink; unpinned.len()],
+                    Length::Shrink,
+                );
+                let content_widths: Vec<f32> = natural.children().iter()
+                    .map(|tab_node| tab_node.size().width)
+                    .collect();
+
+                let (fitting, overflowing) = self.tab_widths(
+                    &content_widths,
+                    tabs_limits.width(self.width).max().width,
+                );
+
+                (
+                    self.tabs_node(renderer, &tabs_limits, &unpinned, &fitting, self.width),
+                    // Lay out the tabs by their minimum width to check if they
+                    // are overflowing.
+                    self.tabs_node(renderer, &unbounded, &unpinned, &overflowing, Length::Shrink),
+                )
+            },
+            Orientation::Vertical => {
+                let tab_widths = vec![self.tab_width; unpinned.len()];
+                let unbounded = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(limits.max().width, f32::INFINITY),
+                );
+
+                (
+                    self.tabs_node(renderer, &tabs_limits, &unpinned, &tab_widths, self.width),
+                    // Lay out the tabs by their content to check if they are
+                    // overflowing.
+                    self.tabs_node(renderer, &unbounded, &unpinned, &tab_widths, Length::Shrink),
+                )
+            },
+        };
+
+        let (_, available) = main_axis(self.orientation, node.bounds());
+        let (_, needed) = main_axis(self.orientation, content.bounds());
+
+        let tabs_size = node.size();
+        let arrow_length = button_length;
+
+        // Moves the given node along the main axis of the TabBar.
+        let shift = |mut node: layout::Node, distance: f32| {
+            let bounds = node.bounds();
+            node.move_to(match self.orientation {
+                Orientation::Horizontal => Point::new(bounds.x + distance, bounds.y),
+                Orientation::Vertical => Point::new(bounds.x, bounds.y + distance),
+            });
+            node
+        };
+
+        let (unpinned_children, arrows) = if unpinned.is_empty() || needed <= available {
+            (node.children().to_vec(), Vec::new())
+        } else {
+            let (arrow_size, forward_offset) = match self.orientation {
+                Orientation::Horizontal => (
+                    Size::new(arrow_length, tabs_size.height),
+                    tabs_size.width - arrow_length,
+                ),
+                Orientation::Vertical => (
+                    Size::new(tabs_size.width, arrow_length),
+                    tabs_size.height - arrow_length,
+                ),
+            };
+
+            let children = content.children().iter()
+                .cloned()
+                .map(|tab_node| shift(tab_node, arrow_length))
+                .collect();
+
+            let back = layout::Node::new(arrow_size);
+            let forward = shift(layout::Node::new(arrow_size), forward_offset);
+
+            (children, vec![back, forward])
+        };
+
+        // The children of the tabs keep the order of the tabs.
+        let mut pinned_children = pinned_node.children().iter().cloned();
+        let mut unpinned_children = unpinned_children.into_iter()
+            .map(|tab_node| shift(tab_node, pinned_length));
+        let mut children: Vec<layout::Node> = (0..self.tabs.len())
+            .filter_map(|index| if self.is_tab_pinned(index) {
+                pinned_children.next()
+            } else {
+                unpinned_children.next()
+            })
+            .collect();
+        let arrows: Vec<layout::Node> = arrows.into_iter()
+            .map(|arrow| shift(arrow, pinned_length))
+            .collect();
+
+        let pinned_size = pinned_node.size();
+        let size = match self.orientation {
+            Orientation::Horizontal => Size::new(
+                pinned_length + tabs_size.width + buttons_length,
+                if self.button_count() > 0 {
+                    tabs_size.height.max(pinned_size.height).max(button_length)
+                } else {
+                    tabs_size.height.max(pinned_size.height)
+                },
+            ),
+            Orientation::Vertical => Size::new(
+                if self.button_count() > 0 {
+                    tabs_size.width.max(pinned_size.width).max(button_length)
+                } else {
+                    tabs_size.width.max(pinned_size.width)
+                },
+                pinned_length + tabs_size.height + buttons_length,
+            ),
+        };
+
+        for i in 0..self.button_count() {
+            let offset = pinned_length + available + i as f32 * button_length;
+            let mut button = match self.orientation {
+                Orientation::Horizontal => layout::Node::new(
+                    Size::new(button_length, size.height),
+                ),
+                Orientation::Vertical => layout::Node::new(
+                    Size::new(size.width, button_length),
+                ),
+            };
+            button.move_to(match self.orientation {
+                Orientation::Horizontal => Point::new(offset, 0.0),
+                Orientation::Vertical => Point::new(0.0, offset),
+            });
+
+            children.push(button);
+        }
+
+        children.extend(arrows);
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>
+    ) -> event::Status {
+        self.state.scroll_offset = self.scroll_offset(layout);
+        self.state.scrolled_to = Some(self.active_tab);
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+            self.state.is_focused = layout.bounds().contains(cursor_position);
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            let tabs_cursor_position = self.tabs_cursor_position(layout, cursor_position);
+
+            self.state.hovered_tab = layout.children()
+                .take(self.tabs.len())
+                .position(|tab_layout| tab_layout.bounds().contains(tabs_cursor_position))
+                .filter(|index| self.is_text_truncated(renderer, layout, *index))
+                .map(|index| (index, cursor_position));
+        }
+
+        if let Some(status) = self.on_keyboard_event(&event, messages) {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+            let (new_button, menu_button) = self.button_bounds(layout);
+
+            if new_button.map_or(false, |bounds| bounds.contains(cursor_position)) {
+                if let Some(on_new) = &self.on_new {
+                    messages.push(on_new());
+                }
+                return event::Status::Captured;
+            }
+
+            if menu_button.map_or(false, |bounds| bounds.contains(cursor_position)) {
+                self.state.menu_open = !self.state.menu_open;
+                self.state.menu_scroll_offset = 0.0;
+                return event::Status::Captured;
+            }
+        }
+
+        if let Some(status) = self.on_drag_event(&event, layout, cursor_position, messages) {
+            return status;
+        }
+
+        if let Some(status) = self.on_dock_event(&event, layout, cursor_position, messages) {
+            return status;
+        }
+
+        if let Some(status) = self.on_scroll_event(&event, layout, cursor_position) {
+            return status;
+        }
+
+        let window_cursor_position = cursor_position;
+        let cursor_position = self.tabs_cursor_position(layout, cursor_position);
+
+        let disabled_tabs = &self.disabled_tabs;
+        let status = self.tabs.iter_mut()
+            .enumerate()
+            .zip(layout.children())
+            .fold(event::Status::Ignored, |status, ((index, tab), tab_layout)| {
+                match tab {
+                    Tab::Element(element) if !disabled_tabs.contains(&index) => {
+                        let label_layout = tab_layout.children().next().unwrap();
+
+                        status.merge(element.on_event(
+                            event.clone(),
+                            label_layout.children().next().unwrap(),
+                            cursor_position,
+                            messages,
+                            renderer,
+                            clipboard,
+                        ))
+                    },
+                    _ => status,
+                }
+            });
+
+        if let event::Status::Captured = status {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let tabs_map: Vec<bool> = layout.children()
+                    .take(self.tabs.len())
+                    .map(|layout| layout.bounds().contains(cursor_position))
+                    .collect();
+
+                if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
+                    if self.is_tab_disabled(new_selected) {
+                        return event::Status::Captured;
+                    }
+
+                    let on_close = self.on_close.as_ref().filter(|_on_close| {
+                        let tab_layout = layout.children().nth(new_selected).unwrap();
+
+                        !self.is_tab_pinned(new_selected)
+                            && tab_layout.children()
+                                .nth(1)
+                                .map_or(false, |cross_layout| {
+                                    cross_layout.bounds().contains(cursor_position)
+                                })
+                    });
+
+                    if let Some(on_close) = on_close {
+                        messages.push((on_close)(new_selected));
+                    } else {
+                        messages.push((self.on_select)(new_selected));
+
+                        let is_draggable = self.on_reorder.is_some() || self.dock.is_some();
+
+                        if is_draggable && !self.is_tab_pinned(new_selected) {
+                            self.state.drag = Some(Drag {
+                                index: new_selected,
+                                origin: window_cursor_position,
+                                position: window_cursor_position,
+                                is_dragging: false,
+                            });
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+            },
+            _ => {}
+        };
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            self.active_tab,
+            &self.tabs,
+            &(0..self.tabs.len())
+                .map(|index| self.tab_status(index))
+                .collect::<Vec<TabStatus>>(),
+            layout,
+            cursor_position,
+            self.icon_font,
+            self.text_font,
+            self.orientation,
+            self.scroll_offset(layout),
+            self.dragged_tab(layout),
+            self.dock_drop_index(layout, cursor_position),
+            self.on_new.is_some(),
+            self.overflow_menu,
+            &self.style,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.active_tab.hash(state);
+        self.tabs.iter().for_each(|tab| match tab {
+            Tab::Label(tab_label) => tab_label.hash(state),
+            Tab::Element(element) => element.hash_layout(state),
+        });
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_height.hash(state);
+        self.icon_size.hash(state);
+        self.text_size.hash(state);
+        self.close_size.hash(state);
+        self.orientation.hash(state);
+        (0..self.tabs.len()).for_each(|index| self.is_tab_modified(index).hash(state));
+        self.on_new.is_some().hash(state);
+        self.overflow_menu.hash(state);
+        self.min_tab_width.hash(state);
+        self.max_tab_width.hash(state);
+        (0..self.tabs.len()).for_each(|index| self.is_tab_pinned(index).hash(state));
+    }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.overflow_menu || !self.state.menu_open {
+            return self.tooltip(layout);
+        }
+
+        let (_, menu_button) = self.button_bounds(layout);
+        let menu_button = menu_button?;
+
+        let position = match self.orientation {
+            Orientation::Horizontal => Point::new(
+                menu_button.x,
+                menu_button.y + menu_button.height,
+            ),
+            Orientation::Vertical => Point::new(
+                menu_button.x + menu_button.width,
+                menu_button.y,
+            ),
+        };
+
+        let tab_statuses = (0..self.tabs.len())
+            .map(|index| self.tab_status(index))
+            .collect();
+
+        Some(
+            TabBarMenuOverlay::new(
+                &mut *self.state,
+                &self.tabs,
+                tab_statuses,
+                self.active_tab,
+                self.on_select.as_ref(),
+                menu_button,
+                self.text_size,
+                self.padding,
+                self.icon_font,
+                self.text_font,
+                &self.style,
+            )
+            .overlay(position)
+        )
+    }
+}
+
+/// The label of a [`Tab::Element`](Tab::Element) borrowed from its
+/// [`TabBar`](TabBar) to be laid out like the other labels.
+struct ElementLabel<'a, 'b, Message, Renderer> {
+    element: &'b Element<'a, Message, Renderer>,
+}
+
+impl<'a, 'b, Message, Renderer> Widget<Message, Renderer>
+    for ElementLabel<'a, 'b, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element.layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.element.draw(renderer, defaults, layout, cursor_position, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.element.hash_layout(state);
+    }
+}
+
+/// Returns the start and the length of the given bounds along the axis of the
+/// given [`Orientation`](Orientation).
+fn main_axis(orientation: Orientation, bounds: Rectangle) -> (f32, f32) {
+    match orientation {
+        Orientation::Horizontal => (bounds.x, bounds.width),
+        Orientation::Vertical => (bounds.y, bounds.height),
+    }
+}
+
+/// Returns the position of the cursor relative to the tabs of a
+/// [`TabBar`](TabBar) that are scrolled by the given offset inside the given
+/// strip.
+/// 
+/// If the cursor is in front of the strip, where the pinned tabs are placed,
+/// the position is not changed. If the cursor is behind the strip, the
+/// returned position will not be contained by any tab.
+pub fn tabs_cursor_position(
+    strip: Option<Rectangle>,
+    scroll_offset: f32,
+    orientation: Orientation,
+    cursor_position: Point,
+) -> Point {
+    match strip {
+        None => cursor_position,
+        Some(strip) if strip.contains(cursor_position) => match orientation {
+            Orientation::Horizontal => Point::new(
+                cursor_position.x + scroll_offset,
+                cursor_position.y,
+            ),
+            Orientation::Vertical => Point::new(
+                cursor_position.x,
+                cursor_position.y + scroll_offset,
+            ),
+        },
+        Some(strip) => {
+            let (cursor, _) = main_axis(
+                orientation,
+                Rectangle::new(cursor_position, Size::ZERO),
+            );
+            let (strip_start, _) = main_axis(orientation, strip);
+
+            if cursor < strip_start {
+                cursor_position
+            } else {
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY)
+            }
+        },
+    }
+}
+
+/// The state of a [`TabBar`](TabBar).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    scroll_offset: f32,
+    scrolled_to: Option<usize>,
+    drag: Option<Drag>,
+    is_focused: bool,
+    menu_open: bool,
+    menu_scroll_offset: f32,
+    hovered_tab: Option<(usize, Point)>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) of a [`TabBar`](TabBar).
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+/// The [`DockGroup`](DockGroup) a [`TabBar`](TabBar) belongs to.
+struct Dock<'a, Message> {
+    group: &'a DockGroup,
+    id: usize,
+    on_move: Box<dyn Fn(TabMove) -> Message>,
+}
+
+/// The state of a tab of a [`TabBar`](TabBar) that is pressed by the user.
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    position: Point,
+    is_dragging: bool,
+}
+
+/// The status of a tab of a [`TabBar`](TabBar) needed to draw it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TabStatus {
+    /// True if the tab is disabled.
+    pub is_disabled: bool,
+
+    /// True if the tab is marked as modified.
+    pub is_modified: bool,
+
+    /// True if the tab can be closed by the user.
+    pub is_closable: bool,
+
+    /// True if the tab is pinned.
+    pub is_pinned: bool,
+}
+
+/// A tab of a [`TabBar`](TabBar) that is currently dragged by the user.
+#[derive(Clone, Copy, Debug)]
+pub struct DraggedTab {
+    /// The index of the dragged tab.
+    pub index: usize,
+
+    /// The index of the tab in front of which the dragged tab would be
+    /// dropped. If it is equal to the number of tabs, the dragged tab would
+    /// be dropped behind the last tab.
+    pub drop_index: usize,
+
+    /// The distance the dragged tab was moved along the
+    /// [`Orientation`](Orientation) of the [`TabBar`](TabBar).
+    pub offset: f32,
+}
+
+/// The renderer of a [`TabBar`](TabBar).
+/// 
+/// Your renderer will need to implement this trait before being
+/// able to use a [`TabBar`](TabBar) in your user interface.
+pub trait Renderer: iced_native::Renderer {
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default icon size of a [`TabBar`](TabBar).
+    const DEFAULT_ICON_SIZE: u16;
+
+    /// The default text size of a [`TabBar`](TabBar).
+    const DEFAULT_TEXT_SIZE: u16;
+
+    /// The default close size of a [`TabBar`](TabBar).
+    const DEFAULT_CLOSE_SIZE: u16;
+
+    /// The default padding of a [`TabBar`](TabBar).
+    const DEFAULT_PADDING: u16;
+
+    /// The default spacing of a [`TabBar`](TabBar).
+    const DEFAULT_SPACING: u16;
+
+    /// Draws a [`TabBar`](TabBar).
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        active_tab: usize,
+        tabs: &[Tab<'_, Message, Self>],
+        tab_statuses: &[TabStatus],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        icon_font: Option<Font>,
+        text_font: Option<Font>,
+        orientation: Orientation,
+        scroll_offset: f32,
+        dragged_tab: Option<DraggedTab>,
+        dock_drop_index: Option<usize>,
+        has_new_button: bool,
+        has_menu_button: bool,
+        style_sheet: &Self::Style,
+        viewport: &Rectangle,
+    ) -> Self::Output;
+
+    /// Draws the menu of a [`TabBar`](TabBar) listing all of its tabs.
+    fn draw_menu<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        active_tab: usize,
+        tabs: &[Tab<'_, Message, Self>],
+        tab_statuses: &[TabStatus],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        icon_font: Option<Font>,
+        text_font: Option<Font>,
+        scroll_offset: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output;
+
+    /// Draws the tooltip of a [`TabBar`](TabBar) showing the full text of a
+    /// truncated tab.
+    fn draw_tooltip(
+        &mut self,
+        defaults: &Self::Defaults,
+        text: &str,
+        layout: Layout<'_>,
+        text_size: u16,
+        text_font: Option<Font>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output;
+}
+
+#[cfg(debug_assertions)]
+impl Renderer for iced_native::renderer::Null {
+    type Style = ();
+
+    const DEFAULT_ICON_SIZE: u16 = 0;
+
+    const DEFAULT_TEXT_SIZE: u16 = 0;
+
+    const DEFAULT_CLOSE_SIZE: u16 = 0;
+
+    const DEFAULT_PADDING: u16 = 0;
+
+    const DEFAULT_SPACING: u16 = 0;
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _active_tab: usize,
+        _tabs: &[Tab<'_, Message, Self>],
+        _tab_statuses: &[TabStatus],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _icon_font: Option<Font>,
+        _text_font: Option<Font>,
+        _orientation: Orientation,
+        _scroll_offset: f32,
+        _dragged_tab: Option<DraggedTab>,
+        _dock_drop_index: Option<usize>,
+        _has_new_button: bool,
+        _has_menu_button: bool,
+        _style_sheet: &Self::Style,
+        _viewport: &Rectangle,
+    ) -> Self::Output {}
+
+    fn draw_menu<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _active_tab: usize,
+        _tabs: &[Tab<'_, Message, Self>],
+        _tab_statuses: &[TabStatus],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _icon_font: Option<Font>,
+        _text_font: Option<Font>,
+        _scroll_offset: f32,
+        _style_sheet: &Self::Style,
+    ) -> Self::Output {}
+
+    fn draw_tooltip(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _text: &str,
+        _layout: Layout<'_>,
+        _text_size: u16,
+        _text_font: Option<Font>,
+        _style_sheet: &Self::Style,
+    ) -> Self::Output {}
+}
+
+
+impl<'a, Message, Renderer> From<TabBar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + column::Renderer + text::Renderer
+        + row::Renderer,
     Message: 'a,
 {
-    fn from(tab_bar: TabBar<Message, Renderer>) -> Self {
+    fn from(tab_bar: TabBar<'a, Message, Renderer>) -> Self {
         Element::new(tab_bar)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::renderer::Null;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Message {
+        Select(usize),
+        Close(usize),
+    }
+
+    /// Creates a [`TabBar`](TabBar) with four tabs.
+    fn tab_bar(state: &mut State, active_tab: usize) -> TabBar<'_, Message, Null> {
+        TabBar::width_tab_labels(
+            state,
+            active_tab,
+            ["One", "Two", "Three", "Four"].iter()
+                .map(|text| TabLabel::Text(String::from(*text)))
+                .collect(),
+            Message::Select,
+        )
+        .on_close(Message::Close)
+    }
+
+    /// Creates the event of pressing the given key.
+    fn press(key_code: keyboard::KeyCode, control: bool, shift: bool) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::ModifiersState {
+                shift,
+                control,
+                alt: false,
+                logo: false,
+            },
+        })
+    }
+
+    /// Returns the messages produced by the given event if it is captured.
+    fn keyboard_messages(tab_bar: &TabBar<'_, Message, Null>, event: Event) -> Option<Vec<Message>> {
+        let mut messages = Vec::new();
+
+        tab_bar.on_keyboard_event(&event, &mut messages)
+            .map(|status| {
+                assert_eq!(status, event::Status::Captured);
+                messages
+            })
+    }
+
+    #[test]
+    fn arrows_only_select_tabs_when_focused() {
+        let mut state = State::new();
+        let mut tab_bar = tab_bar(&mut state, 1);
+        let right = press(keyboard::KeyCode::Right, false, false);
+
+        assert_eq!(keyboard_messages(&tab_bar, right.clone()), None);
+
+        tab_bar.set_focused(true);
+        assert_eq!(keyboard_messages(&tab_bar, right), Some(vec![Message::Select(2)]));
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Left, false, false)),
+            Some(vec![Message::Select(0)]),
+        );
+    }
+
+    #[test]
+    fn arrows_ignore_modified_presses() {
+        let mut state = State::new();
+        let mut tab_bar = tab_bar(&mut state, 1);
+        tab_bar.set_focused(true);
+
+        assert_eq!(keyboard_messages(&tab_bar, press(keyboard::KeyCode::Right, false, true)), None);
+    }
+
+    #[test]
+    fn arrows_skip_disabled_tabs_and_stop_at_the_ends() {
+        let mut state = State::new();
+        let mut tab_bar = tab_bar(&mut state, 0).tab_disabled(1, true);
+        tab_bar.set_focused(true);
+
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Right, false, false)),
+            Some(vec![Message::Select(2)]),
+        );
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Left, false, false)),
+            Some(Vec::new()),
+        );
+    }
+
+    #[test]
+    fn vertical_tab_bar_uses_up_and_down() {
+        let mut state = State::new();
+        let mut tab_bar = tab_bar(&mut state, 1).orientation(Orientation::Vertical);
+        tab_bar.set_focused(true);
+
+        assert_eq!(keyboard_messages(&tab_bar, press(keyboard::KeyCode::Right, false, false)), None);
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Down, false, false)),
+            Some(vec![Message::Select(2)]),
+        );
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Up, false, false)),
+            Some(vec![Message::Select(0)]),
+        );
+    }
+
+    #[test]
+    fn control_tab_cycles_without_focus() {
+        let mut state = State::new();
+        let tab_bar = tab_bar(&mut state, 3);
+
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Tab, true, false)),
+            Some(vec![Message::Select(0)]),
+        );
+
+        let mut state = State::new();
+        let tab_bar = self::tab_bar(&mut state, 0);
+
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Tab, true, true)),
+            Some(vec![Message::Select(3)]),
+        );
+    }
+
+    #[test]
+    fn number_shortcuts_follow_the_display_order() {
+        let mut state = State::new();
+        let tab_bar = tab_bar(&mut state, 0).tab_pinned(2, true);
+
+        assert_eq!(tab_bar.display_order(), vec![2, 0, 1, 3]);
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Key1, true, false)),
+            Some(vec![Message::Select(2)]),
+        );
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Key2, true, false)),
+            Some(vec![Message::Select(0)]),
+        );
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::Key9, true, false)),
+            Some(vec![Message::Select(3)]),
+        );
+    }
+
+    #[test]
+    fn control_w_closes_the_active_tab_unless_pinned() {
+        let mut state = State::new();
+        let tab_bar = tab_bar(&mut state, 1);
+
+        assert_eq!(
+            keyboard_messages(&tab_bar, press(keyboard::KeyCode::W, true, false)),
+            Some(vec![Message::Close(1)]),
+        );
+
+        let mut state = State::new();
+        let tab_bar = self::tab_bar(&mut state, 1).tab_pinned(1, true);
+
+        assert_eq!(keyboard_messages(&tab_bar, press(keyboard::KeyCode::W, true, false)), None);
+    }
+
+    #[test]
+    fn drop_index_skips_the_pinned_tabs() {
+        let mut state = State::new();
+        let tab_bar = tab_bar(&mut state, 0).tab_pinned(2, true);
+
+        // The pinned tab is displayed in front of the others.
+        let tab_bounds: Vec<Rectangle> = [100.0, 200.0, 0.0, 300.0].iter()
+            .map(|x| Rectangle::new(Point::new(*x, 0.0), Size::new(100.0, 30.0)))
+            .collect();
+
+        assert_eq!(tab_bar.drop_index_in(&tab_bounds, 10.0), 0);
+        assert_eq!(tab_bar.drop_index_in(&tab_bounds, 160.0), 1);
+        assert_eq!(tab_bar.drop_index_in(&tab_bounds, 260.0), 3);
+        assert_eq!(tab_bar.drop_index_in(&tab_bounds, 390.0), 4);
+    }
+}
@@ -0,0 +1,125 @@
+//! A [`DockGroup`](DockGroup) for moving tabs between several
+//! [`TabBar`](super::TabBar)s by drag and drop.
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+use std::cell::Cell;
+
+use iced_native::Point;
+
+/// A [`DockGroup`](DockGroup) shared by several [`TabBar`](super::TabBar)s
+/// whose tabs can be dragged from one [`TabBar`](super::TabBar) to another.
+/// 
+/// Keep the [`DockGroup`](DockGroup) in your application state and pass it
+/// to every [`TabBar`](super::TabBar) of the group by calling
+/// [`dock`](super::TabBar::dock) along with an identifier that is unique
+/// within the group.
+#[derive(Debug, Default)]
+pub struct DockGroup {
+    drag: Cell<Option<DockDrag>>,
+}
+
+impl DockGroup {
+    /// Creates a new [`DockGroup`](DockGroup).
+    pub fn new() -> Self {
+        DockGroup::default()
+    }
+
+    /// Returns the identifier of the [`TabBar`](super::TabBar) and the index
+    /// of the tab that is currently dragged within the
+    /// [`DockGroup`](DockGroup).
+    pub fn dragged_tab(&self) -> Option<(usize, usize)> {
+        self.drag.get()
+            .filter(|drag| !drag.is_released)
+            .map(|drag| (drag.source, drag.index))
+    }
+
+    /// Returns the tab that is currently dragged within the
+    /// [`DockGroup`](DockGroup), including a released tab that was not
+    /// dropped yet.
+    pub(crate) fn drag(&self) -> Option<DockDrag> {
+        self.drag.get()
+    }
+
+    /// Sets the tab that is currently dragged within the
+    /// [`DockGroup`](DockGroup).
+    pub(crate) fn set_drag(&self, drag: Option<DockDrag>) {
+        self.drag.set(drag);
+    }
+
+    /// Takes the dragged tab out of the [`DockGroup`](DockGroup) to drop it.
+    pub(crate) fn take_drag(&self) -> Option<DockDrag> {
+        self.drag.take()
+    }
+}
+
+/// A tab dragged out of a [`TabBar`](super::TabBar) of a
+/// [`DockGroup`](DockGroup).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DockDrag {
+    /// The identifier of the [`TabBar`](super::TabBar) the tab is dragged
+    /// from.
+    pub(crate) source: usize,
+
+    /// The index of the dragged tab.
+    pub(crate) index: usize,
+
+    /// The current position of the cursor.
+    pub(crate) position: Point,
+
+    /// Whether the mouse button was released while the cursor was outside
+    /// of the [`TabBar`](super::TabBar) the tab is dragged from.
+    pub(crate) is_released: bool,
+}
+
+/// A [`TabMove`](TabMove) describing a tab that was dragged from one
+/// [`TabBar`](super::TabBar) of a [`DockGroup`](DockGroup) and dropped
+/// somewhere else.
+/// 
+/// The widgets do not move the tab by themselves, so the application has to
+/// remove the tab from its source and insert it at its target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TabMove {
+    /// The identifier of the [`TabBar`](super::TabBar) the tab was dragged
+    /// from.
+    pub source: usize,
+
+    /// The index of the tab on the [`TabBar`](super::TabBar) it was dragged
+    /// from.
+    pub index: usize,
+
+    /// The identifier of the [`TabBar`](super::TabBar) the tab was dropped
+    /// on.
+    pub target: usize,
+
+    /// Where the tab was dropped.
+    pub drop: DropTarget,
+}
+
+/// The target a tab of a [`DockGroup`](DockGroup) was dropped on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DropTarget {
+    /// The tab was dropped on the tabs and should be inserted at the given
+    /// index.
+    Index(usize),
+
+    /// The tab was dropped on the given edge of the content of a
+    /// [`Tabs`](crate::native::tabs::Tabs) widget and should be placed in a
+    /// new group split off at this edge.
+    Split(Edge),
+}
+
+/// An edge of the content of a [`Tabs`](crate::native::tabs::Tabs) widget.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum Edge {
+    /// The top edge.
+    Top,
+
+    /// The bottom edge.
+    Bottom,
+
+    /// The left edge.
+    Left,
+
+    /// The right edge.
+    Right,
+}
\ No newline at end of file
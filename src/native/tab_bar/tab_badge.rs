@@ -0,0 +1,16 @@
+//! A [`TabBadge`](TabBadge) shown in the corner of a
+//! [`TabLabel`](super::TabLabel).
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+
+/// A [`TabBadge`](TabBadge) shown in the corner of a
+/// [`TabLabel`](super::TabLabel) on a [`TabBar`](super::TabBar), e.g. to
+/// indicate unread content.
+#[derive(Clone, Copy, Debug, Hash)]
+pub enum TabBadge {
+    /// A [`TabBadge`](TabBadge) showing a count.
+    Count(u32),
+
+    /// A [`TabBadge`](TabBadge) showing only a dot.
+    Dot,
+}
\ No newline at end of file
@@ -7,19 +7,24 @@
 //! *This API requires the following crate features to be activated: tabs*
 use std::hash::Hash;
 
-use iced_native::{Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Row, Size, Widget, column, event, row, text};
+use iced_native::{Clipboard, Element, Event, Font, Layout, Length, Point, Rectangle, Row, Size, Widget, column, event, mouse, overlay, row, text};
 
-use crate::native::{TabBar, TabLabel};
+use crate::native::{TabBar, TabLabel, tab_bar::{self, DockGroup, DropTarget, Edge, Orientation, TabMove}};
 
 pub mod tab_bar_position;
 pub use tab_bar_position::TabBarPosition;
 
+/// The relative distance to an edge of the content of the [`Tabs`](Tabs) in
+/// which a tab of a [`DockGroup`](super::tab_bar::DockGroup) is dropped to
+/// split the content at this edge.
+const DOCK_EDGE_SIZE: f32 = 0.25;
+
 /// A [`Tabs`](Tabs) widget for showing a [`TabBar`](super::tab_bar::TabBar)
 /// along with the tab's content.
 /// 
 /// # Example
 /// ```
-/// # use iced_aw::{TabLabel};
+/// # use iced_aw::{TabLabel, native::tab_bar};
 /// # use iced_native::{renderer::Null, Text};
 /// #
 /// # pub type Tabs<'a, Message> = iced_aw::native::Tabs<'a, Message, Null>;
@@ -28,9 +33,11 @@ pub use tab_bar_position::TabBarPosition;
 ///     TabSelected(usize),
 /// }
 /// 
+/// let mut state = tab_bar::State::new();
 /// let active_tab = 0;
 /// 
 /// let tabs = Tabs::new(
+///     &mut state,
 ///     active_tab,
 ///     Message::TabSelected,
 /// )
@@ -41,8 +48,8 @@ pub use tab_bar_position::TabBarPosition;
 /// 
 #[allow(missing_debug_implementations)]
 pub struct Tabs<'a, Message, Renderer: self::Renderer> {
-    tab_bar: TabBar<Message, Renderer>,
-    tabs: Vec<Element<'a, Message, Renderer>>,
+    tab_bar: TabBar<'a, Message, Renderer>,
+    content: Content<'a, Message, Renderer>,
     tab_bar_position: TabBarPosition,
     width: Length,
     height: Length,
@@ -52,19 +59,21 @@ impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
 {
-    /// Creates a new [`Tabs`](Tabs) widget with the index of the selected tab
+    /// Creates a new [`Tabs`](Tabs) widget with the [`State`](super::tab_bar::State)
+    /// of its [`TabBar`](super::tab_bar::TabBar), the index of the selected tab
     /// and a specified message which will be send when a tab is selected by
     /// the user.
-    pub fn new<F>(active_tab: usize, on_select: F) -> Self
+    pub fn new<F>(state: &'a mut tab_bar::State, active_tab: usize, on_select: F) -> Self
     where
         F: 'static + Fn(usize) -> Message,
     {
-        Self::with_tabs(active_tab, Vec::new(), on_select)
+        Self::with_tabs(state, active_tab, Vec::new(), on_select)
     }
 
     /// Similar to `new` but with a given Vector of the
     /// [`TabLabel`](super::tab_bar::TabLabel) along with the tab's content.
     pub fn with_tabs<F>(
+        state: &'a mut tab_bar::State,
         active_tab: usize,
         tabs: Vec<(TabLabel, Element<'a, Message, Renderer>)>,
         on_select: F,
@@ -81,14 +90,90 @@ where
         }
 
         Tabs {
-            tab_bar: TabBar::width_tab_labels(active_tab, tab_labels, on_select),
-            tabs: elements,
+            tab_bar: TabBar::width_tab_labels(
+                state,
+                active_tab,
+                tab_labels,
+                on_select,
+            ),
+            content: Content::Eager(elements),
+            tab_bar_position: TabBarPosition::Top,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Creates a new lazy [`Tabs`](Tabs) widget with the given
+    /// [`TabLabel`](super::tab_bar::TabLabel)s.
+    /// 
+    /// Unlike [`with_tabs`](Tabs::with_tabs), only the content of the active
+    /// tab is built by calling `content` with its index. This avoids building
+    /// the content of all tabs on every `view`. As `content` is called at
+    /// most once, the returned [`Element`](iced_native::Element) may borrow
+    /// mutable state of the application.
+    /// 
+    /// Tabs added later with [`push`](Tabs::push) or
+    /// [`push_element`](Tabs::push_element) only keep their content if they
+    /// are the active tab.
+    pub fn lazy<C, F>(
+        state: &'a mut tab_bar::State,
+        active_tab: usize,
+        tab_labels: Vec<TabLabel>,
+        content: C,
+        on_select: F,
+    ) -> Self
+    where
+        C: FnOnce(usize) -> Element<'a, Message, Renderer>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        let active_content = if active_tab < tab_labels.len() {
+            Some(content(active_tab))
+        } else {
+            None
+        };
+
+        Tabs {
+            tab_bar: TabBar::width_tab_labels(
+                state,
+                active_tab,
+                tab_labels,
+                on_select,
+            ),
+            content: Content::Lazy(active_content),
             tab_bar_position: TabBarPosition::Top,
             width: Length::Fill,
             height: Length::Fill,
         }
     }
 
+    /// Sets whether the tab at the given index is disabled.
+    /// 
+    /// A disabled tab can neither be selected nor closed by the user and is
+    /// skipped by the keyboard navigation.
+    pub fn tab_disabled(mut self, index: usize, disabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.tab_disabled(index, disabled);
+        self
+    }
+
+    /// Sets whether the tab at the given index is marked as modified.
+    /// 
+    /// A modified tab shows an indicator in place of its close icon until
+    /// the tab is hovered.
+    pub fn tab_modified(mut self, index: usize, modified: bool) -> Self {
+        self.tab_bar = self.tab_bar.tab_modified(index, modified);
+        self
+    }
+
+    /// Sets whether the tab at the given index is pinned.
+    /// 
+    /// A pinned tab only shows the icon of its label and is placed in front
+    /// of the unpinned tabs, where it keeps its position while the tabs are
+    /// scrolled. It can neither be closed nor dragged by the user.
+    pub fn tab_pinned(mut self, index: usize, pinned: bool) -> Self {
+        self.tab_bar = self.tab_bar.tab_pinned(index, pinned);
+        self
+    }
+
     /// Sets the message that will be produced when the close icon of a tab
     /// on the [`TabBar`](TabBar) is pressed.
     /// 
@@ -101,6 +186,64 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab on the
+    /// [`TabBar`](TabBar) is dragged to a new position.
+    /// 
+    /// The message is created from the index the tab was dragged from and
+    /// the index it should be moved to. The [`Tabs`](Tabs) do not reorder
+    /// their content by themselves, so the application has to move its tab
+    /// at `from` to `to`.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_reorder(on_reorder);
+        self
+    }
+
+    /// Sets the message that will be produced when the button for creating
+    /// a new tab on the [`TabBar`](TabBar) is pressed.
+    /// 
+    /// The [`Tabs`](Tabs) do not add a tab by themselves, so the application
+    /// has to push the new tab and its content.
+    pub fn on_new(mut self, on_new: Message) -> Self
+    where
+        Message: 'static + Clone,
+    {
+        self.tab_bar = self.tab_bar.on_new(on_new);
+        self
+    }
+
+    /// Sets whether the [`TabBar`](TabBar) shows a button opening a menu
+    /// that lists all tabs, including those scrolled out of view.
+    /// 
+    /// Only one overlay can be shown at a time. The open menu is shown on
+    /// top of the content, hiding its overlay, like the button of a
+    /// `FloatingButton`. Otherwise, an overlay of the content hides the
+    /// tooltips of truncated tabs.
+    pub fn overflow_menu(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.overflow_menu(enabled);
+        self
+    }
+
+    /// Adds the [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs) to
+    /// the given [`DockGroup`](super::tab_bar::DockGroup) using the given
+    /// identifier, which has to be unique within the group.
+    /// 
+    /// Besides the tabs, a dragged tab can be dropped on the content of the
+    /// [`Tabs`](Tabs). Dropping it near an edge of the content requests a new
+    /// group split off at this edge, dropping it anywhere else appends it to
+    /// the tabs. The [`Tabs`](Tabs) do not move the tab or its content by
+    /// themselves, so the application has to apply the
+    /// [`TabMove`](super::tab_bar::TabMove).
+    pub fn dock<F>(mut self, group: &'a DockGroup, id: usize, on_move: F) -> Self
+    where
+        F: 'static + Fn(TabMove) -> Message,
+    {
+        self.tab_bar = self.tab_bar.dock(group, id, on_move);
+        self
+    }
+
     /// Sets the width of the [`Tabs`](Tabs).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -127,6 +270,27 @@ where
         self
     }
 
+    /// Sets the width of a tab on the [`TabBar`](super::tab_bar::TabBar) of
+    /// the [`Tabs`](Tabs).
+    pub fn tab_width(mut self, width: Length) -> Self {
+        self.tab_bar = self.tab_bar.tab_width(width);
+        self
+    }
+
+    /// Sets the minimum width of each tab on a horizontal
+    /// [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs).
+    pub fn min_tab_width(mut self, min_tab_width: u16) -> Self {
+        self.tab_bar = self.tab_bar.min_tab_width(min_tab_width);
+        self
+    }
+
+    /// Sets the maximum width of each tab on a horizontal
+    /// [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs).
+    pub fn max_tab_width(mut self, max_tab_width: u16) -> Self {
+        self.tab_bar = self.tab_bar.max_tab_width(max_tab_width);
+        self
+    }
+
     /// Sets the maximum height of the [`TabBar`](super::tab_bar::TabBar) of the
     /// [`Tabs`](Tabs).
     pub fn tab_bar_max_height(mut self, max_height: u32) -> Self {
@@ -185,6 +349,46 @@ where
         self
     }
 
+    /// Sets whether the tabs can be cycled with `Ctrl+Tab` and
+    /// `Ctrl+Shift+Tab`.
+    /// 
+    /// The shortcuts of the [`Tabs`](Tabs) are ignored if the content of the
+    /// active tab handles the key, like a focused text input.
+    /// 
+    /// This is enabled by default.
+    pub fn cycle_shortcuts(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.cycle_shortcuts(enabled);
+        self
+    }
+
+    /// Sets whether the first eight tabs can be selected with `Ctrl+1` to
+    /// `Ctrl+8` and the last tab with `Ctrl+9`.
+    /// 
+    /// This is enabled by default.
+    pub fn number_shortcuts(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.number_shortcuts(enabled);
+        self
+    }
+
+    /// Sets whether the active tab can be closed with `Ctrl+W`. This only has
+    /// an effect if [`on_close`](Tabs::on_close) is set.
+    /// 
+    /// This is enabled by default.
+    pub fn close_shortcut(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.close_shortcut(enabled);
+        self
+    }
+
+    /// Sets whether the arrow keys select the previous or next tab while the
+    /// [`Tabs`](Tabs) are focused and the content of the active tab does not
+    /// handle the key. The [`Tabs`](Tabs) get focused by clicking on them.
+    /// 
+    /// This is enabled by default.
+    pub fn arrow_navigation(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.arrow_navigation(enabled);
+        self
+    }
+
     /// Sets the style of the [`TabBar`](super::tab_bar::TabBar).
     pub fn tab_bar_style<T>(mut self, style: T) -> Self
     where
@@ -196,21 +400,170 @@ where
 
     /// Sets the [`TabBarPosition`](TabBarPosition) of the
     /// [`TabBar`](super::tab_bar::TabBar).
+    /// 
+    /// Placing the [`TabBar`](super::tab_bar::TabBar) on the left or right
+    /// side of the content will change its
+    /// [`Orientation`](super::tab_bar::Orientation) to vertical. You may want
+    /// to restrict its width by using [`tab_bar_width`](Tabs::tab_bar_width)
+    /// and [`tab_width`](Tabs::tab_width).
     pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
+        self.tab_bar = self.tab_bar.orientation(match position {
+            TabBarPosition::Top | TabBarPosition::Bottom => Orientation::Horizontal,
+            TabBarPosition::Left | TabBarPosition::Right => Orientation::Vertical,
+        });
         self.tab_bar_position = position;
         self
     }
 
+    /// Returns the [`DropTarget`](super::tab_bar::DropTarget) and the zone to
+    /// highlight for a tab of the [`DockGroup`](super::tab_bar::DockGroup)
+    /// dragged to the given position on the content of the [`Tabs`](Tabs).
+    fn dock_zone(
+        &self,
+        content_bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<(DropTarget, Rectangle)> {
+        let (group, id) = self.tab_bar.get_dock()?;
+        let drag = group.drag()?;
+
+        if !content_bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let x = (cursor_position.x - content_bounds.x) / content_bounds.width;
+        let y = (cursor_position.y - content_bounds.y) / content_bounds.height;
+
+        let (edge, distance) = vec![
+            (Edge::Left, x),
+            (Edge::Right, 1.0 - x),
+            (Edge::Top, y),
+            (Edge::Bottom, 1.0 - y),
+        ]
+        .into_iter()
+        .fold((Edge::Left, f32::INFINITY), |closest, (edge, distance)| {
+            if distance < closest.1 {
+                (edge, distance)
+            } else {
+                closest
+            }
+        });
+
+        if distance < DOCK_EDGE_SIZE {
+            let half_width = content_bounds.width / 2.0;
+            let half_height = content_bounds.height / 2.0;
+
+            let zone = match edge {
+                Edge::Left => Rectangle {
+                    width: half_width,
+                    .. content_bounds
+                },
+                Edge::Right => Rectangle {
+                    x: content_bounds.x + half_width,
+                    width: half_width,
+                    .. content_bounds
+                },
+                Edge::Top => Rectangle {
+                    height: half_height,
+                    .. content_bounds
+                },
+                Edge::Bottom => Rectangle {
+                    y: content_bounds.y + half_height,
+                    height: half_height,
+                    .. content_bounds
+                },
+            };
+
+            Some((DropTarget::Split(edge), zone))
+        } else if drag.source != id {
+            Some((DropTarget::Index(self.tab_bar.tab_count()), content_bounds))
+        } else {
+            // The tab is already part of these tabs.
+            None
+        }
+    }
+
+    /// Returns the content of the active tab of the [`Tabs`](Tabs).
+    fn active_content(&self) -> Option<&Element<'a, Message, Renderer>> {
+        match &self.content {
+            Content::Eager(elements) => elements.get(self.tab_bar.get_active_tab()),
+            Content::Lazy(content) => content.as_ref(),
+        }
+    }
+
+    /// Returns the mutable content of the active tab of the [`Tabs`](Tabs).
+    fn active_content_mut(&mut self) -> Option<&mut Element<'a, Message, Renderer>> {
+        match &mut self.content {
+            Content::Eager(elements) => elements.get_mut(self.tab_bar.get_active_tab()),
+            Content::Lazy(content) => content.as_mut(),
+        }
+    }
+
+    /// Adds the content of a tab pushed at the given index.
+    /// 
+    /// Lazy [`Tabs`](Tabs) only keep the content if it belongs to the
+    /// active tab and no content was built yet.
+    fn push_content(&mut self, index: usize, element: Element<'a, Message, Renderer>) {
+        match &mut self.content {
+            Content::Eager(elements) => elements.push(element),
+            Content::Lazy(content) => {
+                if content.is_none() && index == self.tab_bar.get_active_tab() {
+                    *content = Some(element);
+                }
+            },
+        }
+    }
+
     /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with the tabs
     /// content to the [`Tabs`](Tabs).
+    /// 
+    /// If the [`Tabs`](Tabs) were created with [`lazy`](Tabs::lazy), the
+    /// content is dropped unless the pushed tab is the active tab.
     pub fn push<E>(mut self, tab_label: TabLabel, element: E) -> Self
     where
         E: Into<Element<'a, Message, Renderer>>,
     {
+        let index = self.tab_bar.tab_count();
         self.tab_bar = self.tab_bar.push(tab_label);
-        self.tabs.push(element.into());
+        self.push_content(index, element.into());
         self
     }
+
+    /// Pushes an arbitrary [`Element`](iced_native::Element) as the label of
+    /// a tab along with the tabs content to the [`Tabs`](Tabs).
+    /// 
+    /// If the [`Tabs`](Tabs) were created with [`lazy`](Tabs::lazy), the
+    /// content is dropped unless the pushed tab is the active tab.
+    pub fn push_element<L, E>(mut self, label: L, element: E) -> Self
+    where
+        L: Into<Element<'a, Message, Renderer>>,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let index = self.tab_bar.tab_count();
+        self.tab_bar = self.tab_bar.push_element(label);
+        self.push_content(index, element.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + column::Renderer + text::Renderer + row::Renderer,
+{
+    /// Lays out the content of the active tab of the [`Tabs`](Tabs).
+    fn tab_content_node(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        if let Some(element) = self.active_content() {
+            element.layout(renderer, limits)
+        } else {
+            Row::<Message, Renderer>::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .layout(renderer, limits)
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Tabs<'a, Message, Renderer>
@@ -230,52 +583,90 @@ where
         renderer: &Renderer,
         limits: &iced_native::layout::Limits,
     ) -> iced_native::layout::Node {
-        let tab_bar_limits = limits.clone()
-            .width(self.width)
-            .height(self.tab_bar.get_height());
-
-        let mut tab_bar_node = self.tab_bar.layout(renderer, &tab_bar_limits);
-
-        let tab_content_limits = limits.clone()
-            .shrink(Size::new(0.0, tab_bar_node.size().height))
-            .width(self.width)
-            .height(self.height);
-
-        let mut tab_content_node = if let Some(element) = self.tabs.get(self.tab_bar.get_active_tab()) {
-            element.layout(renderer, &tab_content_limits)
-        } else {
-            Row::<Message, Renderer>::new()
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .layout(renderer, &tab_content_limits)       
-        };
-
-        tab_bar_node.move_to(Point::new(
-            tab_bar_node.bounds().x,
-            tab_bar_node.bounds().y + match self.tab_bar_position {
-                TabBarPosition::Top => 0.0,
-                TabBarPosition::Bottom => tab_content_node.bounds().height,
-            }
-        ));
-
-        tab_content_node.move_to(Point::new(
-            tab_content_node.bounds().x,
-            tab_content_node.bounds().y + match self.tab_bar_position {
-                TabBarPosition::Top => tab_bar_node.bounds().height,
-                TabBarPosition::Bottom => 0.0,
-            }
-        ));
-
-        iced_native::layout::Node::with_children(
-            Size::new(
-                tab_content_node.size().width,
-                tab_bar_node.size().height + tab_content_node.size().height,
-            ),
-            match self.tab_bar_position {
-                TabBarPosition::Top => vec!(tab_bar_node, tab_content_node),
-                TabBarPosition::Bottom => vec!(tab_content_node, tab_bar_node)
+        match self.tab_bar_position {
+            TabBarPosition::Top | TabBarPosition::Bottom => {
+                let tab_bar_limits = limits.clone()
+                    .width(self.width)
+                    .height(self.tab_bar.get_height());
+
+                let mut tab_bar_node = self.tab_bar.layout(renderer, &tab_bar_limits);
+
+                let tab_content_limits = limits.clone()
+                    .shrink(Size::new(0.0, tab_bar_node.size().height))
+                    .width(self.width)
+                    .height(self.height);
+
+                let mut tab_content_node = self.tab_content_node(renderer, &tab_content_limits);
+
+                tab_bar_node.move_to(Point::new(
+                    tab_bar_node.bounds().x,
+                    tab_bar_node.bounds().y + match self.tab_bar_position {
+                        TabBarPosition::Bottom => tab_content_node.bounds().height,
+                        _ => 0.0,
+                    }
+                ));
+
+                tab_content_node.move_to(Point::new(
+                    tab_content_node.bounds().x,
+                    tab_content_node.bounds().y + match self.tab_bar_position {
+                        TabBarPosition::Top => tab_bar_node.bounds().height,
+                        _ => 0.0,
+                    }
+                ));
+
+                iced_native::layout::Node::with_children(
+                    Size::new(
+                        tab_content_node.size().width,
+                        tab_bar_node.size().height + tab_content_node.size().height,
+                    ),
+                    match self.tab_bar_position {
+                        TabBarPosition::Top => vec!(tab_bar_node, tab_content_node),
+                        _ => vec!(tab_content_node, tab_bar_node),
+                    },
+                )
             },
-        )
+            TabBarPosition::Left | TabBarPosition::Right => {
+                let tab_bar_limits = limits.clone()
+                    .width(self.tab_bar.get_width())
+                    .height(self.height);
+
+                let mut tab_bar_node = self.tab_bar.layout(renderer, &tab_bar_limits);
+
+                let tab_content_limits = limits.clone()
+                    .shrink(Size::new(tab_bar_node.size().width, 0.0))
+                    .width(self.width)
+                    .height(self.height);
+
+                let mut tab_content_node = self.tab_content_node(renderer, &tab_content_limits);
+
+                tab_bar_node.move_to(Point::new(
+                    tab_bar_node.bounds().x + match self.tab_bar_position {
+                        TabBarPosition::Right => tab_content_node.bounds().width,
+                        _ => 0.0,
+                    },
+                    tab_bar_node.bounds().y,
+                ));
+
+                tab_content_node.move_to(Point::new(
+                    tab_content_node.bounds().x + match self.tab_bar_position {
+                        TabBarPosition::Left => tab_bar_node.bounds().width,
+                        _ => 0.0,
+                    },
+                    tab_content_node.bounds().y,
+                ));
+
+                iced_native::layout::Node::with_children(
+                    Size::new(
+                        tab_bar_node.size().width + tab_content_node.size().width,
+                        tab_bar_node.size().height.max(tab_content_node.size().height),
+                    ),
+                    match self.tab_bar_position {
+                        TabBarPosition::Left => vec!(tab_bar_node, tab_content_node),
+                        _ => vec!(tab_content_node, tab_bar_node),
+                    },
+                )
+            },
+        }
     }
 
     fn on_event(
@@ -289,30 +680,45 @@ where
     ) -> event::Status {
         let mut children = layout.children();
         let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
-            TabBarPosition::Top => {
+            TabBarPosition::Top | TabBarPosition::Left => {
                 let tab_bar_layout = children.next().unwrap();
                 let tab_content_layout = children.next().unwrap();
                 (tab_bar_layout, tab_content_layout)
             }
-            TabBarPosition::Bottom => {
+            TabBarPosition::Bottom | TabBarPosition::Right => {
                 let tab_content_layout = children.next().unwrap();
                 let tab_bar_layout = children.next().unwrap();
                 (tab_bar_layout, tab_content_layout)
             }
         };
 
-        let status_tab_bar = self.tab_bar.on_event(
-            event.clone(),
-            tab_bar_layout,
-            cursor_position,
-            messages,
-            renderer,
-            clipboard,
+        let is_released = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         );
 
-        let status_element = if let Some(element) = self.tabs.get_mut(self.tab_bar.get_active_tab()) {
+        let is_pressed = matches!(event, Event::Mouse(mouse::Event::ButtonPressed(_)));
+
+        // The content handles the keyboard first, so the shortcuts of the
+        // TabBar don't interfere with a focused text input.
+        let is_keyboard = matches!(event, Event::Keyboard(_));
+
+        let status_tab_bar = if is_keyboard {
+            event::Status::Ignored
+        } else {
+            self.tab_bar.on_event(
+                event.clone(),
+                tab_bar_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            )
+        };
+
+        let status_element = if let Some(element) = self.active_content_mut() {
             element.on_event(
-                event,
+                event.clone(),
                 tab_content_layout,
                 cursor_position,
                 messages,
@@ -323,7 +729,41 @@ where
             event::Status::Ignored
         };
 
-        status_tab_bar.merge(status_element)
+        let status_tab_bar = match status_element {
+            event::Status::Ignored if is_keyboard => self.tab_bar.on_event(
+                event,
+                tab_bar_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            ),
+            _ => status_tab_bar,
+        };
+
+        // Clicking anywhere on the Tabs focuses their TabBar.
+        if is_pressed {
+            self.tab_bar.set_focused(layout.bounds().contains(cursor_position));
+        }
+
+        let dock_message = Some(is_released)
+            .filter(|is_released| *is_released)
+            .and_then(|_| self.dock_zone(tab_content_layout.bounds(), cursor_position))
+            .and_then(|(drop, _)| {
+                let (group, _) = self.tab_bar.get_dock()?;
+                let drag = group.take_drag()?;
+
+                self.tab_bar.move_message(drag, drop)
+            });
+
+        let status_dock = if let Some(message) = dock_message {
+            messages.push(message);
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        };
+
+        status_tab_bar.merge(status_element).merge(status_dock)
     }
 
     fn draw(
@@ -335,11 +775,25 @@ where
         viewport: &Rectangle,
     ) -> Renderer::Output {
         let mut children = layout.children();
-        let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children.next().unwrap(),
-            TabBarPosition::Bottom => children.last().unwrap()
+        let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
+            TabBarPosition::Top | TabBarPosition::Left => {
+                let tab_bar_layout = children.next().unwrap();
+                let tab_content_layout = children.next().unwrap();
+                (tab_bar_layout, tab_content_layout)
+            }
+            TabBarPosition::Bottom | TabBarPosition::Right => {
+                let tab_content_layout = children.next().unwrap();
+                let tab_bar_layout = children.next().unwrap();
+                (tab_bar_layout, tab_content_layout)
+            }
         };
 
+        // Only highlight the zone while the tab is still dragged.
+        let drop_zone = self.tab_bar.get_dock()
+            .and_then(|(group, _)| group.dragged_tab())
+            .and_then(|_| self.dock_zone(tab_content_layout.bounds(), cursor_position))
+            .map(|(_, zone)| zone);
+
         let tab_bar = self.tab_bar.draw(
             renderer,
             defaults,
@@ -354,10 +808,12 @@ where
             self.tab_bar.get_active_tab(),
             //&self.tab_bar,
             tab_bar,
-            &self.tabs,
+            self.active_content(),
             layout,
             cursor_position,
             &self.tab_bar_position,
+            drop_zone,
+            self.tab_bar.get_style(),
             viewport
         )
     }
@@ -367,11 +823,59 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.tab_bar.hash_layout(state);
-        self.tabs.iter().for_each(|tab| tab.hash_layout(state));
+        match &self.content {
+            Content::Eager(elements) => elements.iter()
+                .for_each(|element| element.hash_layout(state)),
+            Content::Lazy(content) => content.iter()
+                .for_each(|element| element.hash_layout(state)),
+        }
         self.tab_bar_position.hash(state);
         self.width.hash(state);
         self.height.hash(state);
     }
+
+    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let mut children = layout.children();
+        let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
+            TabBarPosition::Top | TabBarPosition::Left => {
+                let tab_bar_layout = children.next().unwrap();
+                let tab_content_layout = children.next().unwrap();
+                (tab_bar_layout, tab_content_layout)
+            }
+            TabBarPosition::Bottom | TabBarPosition::Right => {
+                let tab_content_layout = children.next().unwrap();
+                let tab_bar_layout = children.next().unwrap();
+                (tab_bar_layout, tab_content_layout)
+            }
+        };
+
+        let active_tab = self.tab_bar.get_active_tab();
+        let content = match &mut self.content {
+            Content::Eager(elements) => elements.get_mut(active_tab),
+            Content::Lazy(content) => content.as_mut(),
+        };
+        let tab_bar = &mut self.tab_bar;
+
+        // Only one overlay can be shown. The overflow menu was just opened by
+        // the user, so it takes precedence over the overlay of the content
+        // (like an open pick list), which in turn hides the tooltips.
+        if tab_bar.is_menu_open() {
+            tab_bar.overlay(tab_bar_layout)
+                .or_else(move || content.and_then(|element| element.overlay(tab_content_layout)))
+        } else {
+            content.and_then(|element| element.overlay(tab_content_layout))
+                .or_else(move || tab_bar.overlay(tab_bar_layout))
+        }
+    }
+}
+
+/// The content of the tabs of a [`Tabs`](Tabs) widget.
+enum Content<'a, Message, Renderer> {
+    /// The content of all tabs.
+    Eager(Vec<Element<'a, Message, Renderer>>),
+
+    /// The content of only the active tab.
+    Lazy(Option<Element<'a, Message, Renderer>>),
 }
 
 /// The renderer of a [`Tabs`](Tabs) widget.
@@ -386,10 +890,12 @@ pub trait Renderer: iced_native::Renderer + crate::native::tab_bar::Renderer {
         active_tab: usize,
         //tab_bar: &TabBar<Message, Self>,
         tab_bar: Self::Output,
-        tabs: &[Element<'_, Message, Self>],
+        content: Option<&Element<'_, Message, Self>>,
         layout: Layout<'_>,
         cursor_position: Point,
         tab_bar_position: &TabBarPosition,
+        drop_zone: Option<Rectangle>,
+        style_sheet: &Self::Style,
         viewport: &Rectangle,
     ) -> Self::Output;
 }
@@ -401,10 +907,12 @@ impl Renderer for iced_native::renderer::Null {
         _defaults: &Self::Defaults,
         _active_tab: usize,
         _tab_bar: Self::Output,
-        _tabs: &[Element<'_, Message, Self>],
+        _content: Option<&Element<'_, Message, Self>>,
         _layout: Layout<'_>,
         _cursor_position: Point,
         _tab_bar_position: &TabBarPosition,
+        _drop_zone: Option<Rectangle>,
+        _style_sheet: &Self::Style,
         _viewport: &Rectangle,
     ) -> Self::Output {}
 }
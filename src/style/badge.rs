@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::shadow::Shadow;
+
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
 #[allow(missing_debug_implementations)]
 pub struct Style {
@@ -24,6 +26,10 @@ pub struct Style {
 
     /// The default text color of the [`Badge`](crate::native::badge::Badge).
     pub text_color: Color,
+
+    /// The drop shadow of the [`Badge`](crate::native::badge::Badge), if it
+    /// is elevated.
+    pub shadow: Option<Shadow>,
 }
 
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
@@ -47,6 +53,7 @@ impl StyleSheet for Default {
             border_width: 1.0,
             border_color: Some([0.8, 0.8, 0.8].into()),
             text_color: Color::BLACK,
+            shadow: None,
         }
     }
 
@@ -90,6 +97,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::PRIMARY.into(),
                 text_color: colors::WHITE,
+                shadow: None,
             }
         }
 
@@ -111,6 +119,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::SECONDARY.into(),
                 text_color: colors::WHITE,
+                shadow: None,
             }
         }
 
@@ -132,6 +141,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::SUCCESS.into(),
                 text_color: colors::WHITE,
+                shadow: None,
             }
         }
 
@@ -153,6 +163,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::DANGER.into(),
                 text_color: colors::WHITE,
+                shadow: None,
             }
         }
 
@@ -174,6 +185,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::WARNING.into(),
                 text_color: colors::BLACK,
+                shadow: None,
             }
         }
 
@@ -195,6 +207,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::INFO.into(),
                 text_color: colors::BLACK,
+                shadow: None,
             }
         }
 
@@ -216,6 +229,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::LIGHT.into(),
                 text_color: colors::BLACK,
+                shadow: None,
             }
         }
 
@@ -237,6 +251,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::DARK.into(),
                 text_color: colors::WHITE,
+                shadow: None,
             }
         }
 
@@ -258,6 +273,7 @@ mod predefined {
                 border_width: 1.0,
                 border_color: colors::WHITE.into(),
                 text_color: colors::BLACK,
+                shadow: None,
             }
         }
 
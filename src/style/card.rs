@@ -6,6 +6,8 @@ use iced_native::{Background, Color};
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background, Color};
 
+use crate::style::shadow::Shadow;
+
 /// The appearance of a [`Card`](crate::native::card::Card).
 #[derive(Debug)]
 pub struct Style {
@@ -41,12 +43,83 @@ pub struct Style {
 
     /// The color of the close icon of the [`Card`](crate::native::card::Card).
     pub close_color: Color,
+
+    /// The background of the media area of the
+    /// [`Card`](crate::native::card::Card), visible around a media that does
+    /// not cover it.
+    /// 
+    /// The top corners of a media area above the head are rounded with the
+    /// `border_radius`. The media itself is kept out of these corners.
+    pub media_background: Background,
+
+    /// The background of the scrollbar of a scrollable body of the
+    /// [`Card`](crate::native::card::Card).
+    pub scrollbar_background: Background,
+
+    /// The border radius of the scrollbar and the scroller of a scrollable
+    /// body of the [`Card`](crate::native::card::Card).
+    pub scrollbar_border_radius: f32,
+
+    /// The color of the scroller of a scrollable body of the
+    /// [`Card`](crate::native::card::Card).
+    pub scroller_color: Color,
+
+    /// The drop shadow of the [`Card`](crate::native::card::Card), if it is
+    /// elevated.
+    pub shadow: Option<Shadow>,
 }
 
 /// The appearance of a [`Card`](crate::native::card::Card).
 pub trait StyleSheet {
     /// The normal appearance of a [`Card`](crate::native::card::Card).
     fn active(&self) -> Style;
+
+    /// The appearance when a clickable
+    /// [`Card`](crate::native::card::Card) is hovered.
+    fn hovered(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            background: darken(active.background, 0.03),
+            .. active
+        }
+    }
+
+    /// The appearance when a clickable
+    /// [`Card`](crate::native::card::Card) is pressed.
+    fn pressed(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            background: darken(active.background, 0.08),
+            .. active
+        }
+    }
+
+    /// The appearance of a selected [`Card`](crate::native::card::Card).
+    /// 
+    /// The border of this appearance is kept while a selected
+    /// [`Card`](crate::native::card::Card) is hovered or pressed.
+    fn selected(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            border_width: active.border_width + 2.0,
+            .. active
+        }
+    }
+}
+
+/// Darkens the color of the given background by the given amount.
+fn darken(background: Background, amount: f32) -> Background {
+    match background {
+        Background::Color(color) => Background::Color(Color {
+            r: (color.r - amount).max(0.0),
+            g: (color.g - amount).max(0.0),
+            b: (color.b - amount).max(0.0),
+            .. color
+        }),
+    }
 }
 
 /// The default appearance of a [`Card`](crate::native::card::Card).
@@ -67,6 +140,19 @@ impl StyleSheet for Default {
             foot_background: Color::TRANSPARENT.into(),
             foot_text_color: Color::BLACK,
             close_color: Color::BLACK,
+            media_background: Color::TRANSPARENT.into(),
+            scrollbar_background: Color::TRANSPARENT.into(),
+            scrollbar_border_radius: 5.0,
+            scroller_color: [0.7, 0.7, 0.7].into(),
+            shadow: None,
+        }
+    }
+
+    fn selected(&self) -> Style {
+        Style {
+            border_width: 3.0,
+            border_color: [0.5, 0.5, 0.5].into(),
+            .. self.active()
         }
     }
 }
@@ -212,6 +298,16 @@ mod predefined {
                 .. Style::default()
             }
         }
+
+        // The border of the active appearance is too bright to mark the
+        // selection.
+        fn selected(&self) -> Style {
+            Style {
+                border_width: 3.0,
+                border_color: colors::SECONDARY,
+                .. self.active()
+            }
+        }
     }
 
     /// The appearance with the [`dark`](colors::DARK) head background of a
@@ -244,5 +340,15 @@ mod predefined {
                 .. Style::default()
             }
         }
+
+        // The border of the active appearance is too bright to mark the
+        // selection.
+        fn selected(&self) -> Style {
+            Style {
+                border_width: 3.0,
+                border_color: colors::SECONDARY,
+                .. self.active()
+            }
+        }
     }
 }
\ No newline at end of file
@@ -15,5 +15,7 @@ pub mod card;
 #[cfg(feature = "modal")]
 pub mod modal;
 
+pub mod shadow;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
\ No newline at end of file
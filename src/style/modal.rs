@@ -7,6 +7,8 @@ use iced_native::Background;
 #[cfg(target_arch = "wasm32")]
 use iced_web::{Background};
 
+use crate::style::shadow::Shadow;
+
 /// The appearance of a [`ModalOverlay`](crate::native::modal::ModalOverlay).
 #[allow(missing_debug_implementations)]
 pub struct Style {
@@ -14,6 +16,16 @@ pub struct Style {
     /// 
     /// This is used to color the backdrop of the modal.
     pub background: Background,
+
+    /// The drop shadow of the content of the
+    /// [`ModalOverlay`](crate::native::modal::ModalOverlay), if it is
+    /// elevated.
+    pub content_shadow: Option<Shadow>,
+
+    /// The border radius of the content of the
+    /// [`ModalOverlay`](crate::native::modal::ModalOverlay) used to round the
+    /// corners of its shadow.
+    pub content_border_radius: f32,
 }
 
 /// The appearance of a [`ModalOverlay`](crate::native::modal::ModalOverlay).
@@ -30,6 +42,8 @@ impl StyleSheet for Default {
     fn active(&self) -> Style {
         Style {
             background: Background::Color([0.87, 0.87, 0.87, 0.30].into()),
+            content_shadow: None,
+            content_border_radius: 0.0,
         }
     }
 }
@@ -0,0 +1,43 @@
+//! The drop shadow of a widget expressing its elevation.
+#[cfg(not(target_arch = "wasm32"))]
+use iced_native::Color;
+#[cfg(target_arch = "wasm32")]
+use iced_web::Color;
+
+/// The drop shadow of a widget separating it from the layer below.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Shadow {
+    /// The color of the shadow at its darkest point.
+    pub color: Color,
+
+    /// The horizontal offset of the shadow.
+    pub offset_x: f32,
+
+    /// The vertical offset of the shadow.
+    pub offset_y: f32,
+
+    /// The distance over which the shadow fades out.
+    pub blur_radius: f32,
+}
+
+impl Shadow {
+    /// Creates the [`Shadow`](Shadow) of a widget lifted by the given
+    /// elevation above the layer below.
+    /// 
+    /// Higher elevations result in larger and softer shadows falling further
+    /// down.
+    pub fn elevation(elevation: f32) -> Self {
+        Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+            offset_x: 0.0,
+            offset_y: elevation / 2.0,
+            blur_radius: elevation * 2.0,
+        }
+    }
+}
+
+impl std::default::Default for Shadow {
+    fn default() -> Self {
+        Shadow::elevation(2.0)
+    }
+}
@@ -22,6 +22,9 @@ pub struct Style {
     /// The border width of the tab bar.
     pub border_width: u16,
 
+    /// The border radius of the tab bar.
+    pub border_radius: u16,
+
     /// The background of the tab labels.
     pub tab_label_background: Background,
 
@@ -31,11 +34,103 @@ pub struct Style {
     /// The border with of the tab labels.
     pub tab_label_border_width: u16,
 
+    /// The border radius of the tab labels.
+    pub tab_label_border_radius: u16,
+
     /// The icon color of the tab labels.
     pub icon_color: Color,
 
     /// The text color of the tab labels.
     pub text_color: Color,
+
+    /// The text color of the active tab label.
+    pub active_text_color: Color,
+
+    /// The color of the indicator of the active tab label. No indicator is
+    /// drawn if this is `None`.
+    pub indicator_color: Option<Color>,
+
+    /// The thickness of the indicator of the active tab label.
+    pub indicator_thickness: u16,
+
+    /// The side of the active tab label the indicator is drawn at.
+    pub indicator_position: IndicatorPosition,
+
+    /// The background of the badges of the tab labels.
+    pub badge_background: Background,
+
+    /// The text color of the badges of the tab labels.
+    pub badge_text_color: Color,
+
+    /// The color of the indicator of modified tab labels.
+    pub modified_color: Color,
+}
+
+/// The side of a tab label at which the indicator of the active tab is
+/// drawn.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum IndicatorPosition {
+    /// The indicator is drawn at the top of the tab label.
+    Top,
+
+    /// The indicator is drawn at the bottom of the tab label.
+    Bottom,
+
+    /// The indicator is drawn at the left side of the tab label.
+    Left,
+
+    /// The indicator is drawn at the right side of the tab label.
+    Right,
+}
+
+impl std::default::Default for IndicatorPosition {
+    fn default() -> Self {
+        IndicatorPosition::Bottom
+    }
+}
+
+/// The appearance of the menu of a [`TabBar`](crate::native::tab_bar::TabBar)
+/// listing all of its tabs.
+#[allow(missing_debug_implementations)]
+pub struct MenuStyle {
+    /// The background of the menu.
+    pub background: Background,
+
+    /// The border color of the menu.
+    pub border_color: Color,
+
+    /// The border width of the menu.
+    pub border_width: u16,
+
+    /// The border radius of the menu.
+    pub border_radius: u16,
+
+    /// The background of the entry of the active tab.
+    pub active_background: Background,
+
+    /// The background of a hovered entry.
+    pub hovered_background: Background,
+
+    /// The icon color of the entries.
+    pub icon_color: Color,
+
+    /// The text color of the entries.
+    pub text_color: Color,
+}
+
+/// The appearance of the zone a tab dragged between the
+/// [`TabBar`](crate::native::tab_bar::TabBar)s of a
+/// [`DockGroup`](crate::native::tab_bar::DockGroup) would be dropped on.
+#[allow(missing_debug_implementations)]
+pub struct DropZoneStyle {
+    /// The background of the drop zone.
+    pub background: Background,
+
+    /// The border color of the drop zone.
+    pub border_color: Color,
+
+    /// The border width of the drop zone.
+    pub border_width: u16,
 }
 
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
@@ -49,6 +144,82 @@ pub trait StyleSheet {
     /// 
     /// `is_active` is true if the tab is selected.
     fn hovered(&self, is_active: bool) -> Style;
+
+    /// The appearance of a disabled tab label.
+    /// 
+    /// `is_active` is true if the tab is selected.
+    fn disabled(&self, is_active: bool) -> Style {
+        let active = self.active(is_active);
+
+        Style {
+            tab_label_background: match active.tab_label_background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    .. color
+                }),
+            },
+            icon_color: Color {
+                a: active.icon_color.a * 0.5,
+                .. active.icon_color
+            },
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                .. active.text_color
+            },
+            active_text_color: Color {
+                a: active.active_text_color.a * 0.5,
+                .. active.active_text_color
+            },
+            indicator_color: active.indicator_color.map(|color| Color {
+                a: color.a * 0.5,
+                .. color
+            }),
+            .. active
+        }
+    }
+
+    /// The appearance of a pinned tab label.
+    /// 
+    /// `is_active` is true if the tab is selected.
+    fn pinned(&self, is_active: bool) -> Style {
+        self.active(is_active)
+    }
+
+    /// The appearance of a hovered pinned tab label.
+    /// 
+    /// `is_active` is true if the tab is selected.
+    fn pinned_hovered(&self, is_active: bool) -> Style {
+        Style {
+            tab_label_background: self.hovered(is_active).tab_label_background,
+            .. self.pinned(is_active)
+        }
+    }
+
+    /// The appearance of the menu listing all tabs.
+    /// 
+    /// The tooltip showing the full text of a truncated tab uses the same
+    /// background, border and text color.
+    fn menu(&self) -> MenuStyle {
+        MenuStyle {
+            background: Background::Color(Color::WHITE),
+            border_color: [0.7, 0.7, 0.7].into(),
+            border_width: 1,
+            border_radius: 5,
+            active_background: Background::Color([0.9, 0.9, 0.9].into()),
+            hovered_background: Background::Color([0.87, 0.87, 0.87].into()),
+            icon_color: Color::BLACK,
+            text_color: Color::BLACK,
+        }
+    }
+
+    /// The appearance of the zone a dragged tab would be dropped on.
+    fn drop_zone(&self) -> DropZoneStyle {
+        DropZoneStyle {
+            background: Background::Color(Color::from_rgba(0.2, 0.5, 1.0, 0.2)),
+            border_color: Color::from_rgba(0.2, 0.5, 1.0, 0.6),
+            border_width: 2,
+        }
+    }
 }
 
 struct Default;
@@ -59,6 +230,7 @@ impl StyleSheet for Default {
             background: None,
             border_color: None,
             border_width: 0,
+            border_radius: 0,
             tab_label_background: if is_active {
                 Background::Color([0.9, 0.9, 0.9].into())
             } else {
@@ -66,9 +238,16 @@ impl StyleSheet for Default {
             },
             tab_label_border_color: [0.7, 0.7, 0.7].into(),
             tab_label_border_width: 1,
+            tab_label_border_radius: 0,
             icon_color: Color::BLACK,
             text_color: Color::BLACK,
-
+            active_text_color: Color::BLACK,
+            indicator_color: None,
+            indicator_thickness: 0,
+            indicator_position: IndicatorPosition::Bottom,
+            badge_background: Background::Color([0.85, 0.2, 0.2].into()),
+            badge_text_color: Color::WHITE,
+            modified_color: Color::BLACK,
         }
     }
 
@@ -112,7 +112,7 @@ where
                 bumpalo::format!(
                     in bump,
                     "background: {}; border-radius: {}rem; width:{}; height: {} \
-                    border: {}px solid {}; display: inline-block; color: {}",
+                    border: {}px solid {}; display: inline-block; color: {}; {}",
                     //css::color(style.background)
                     match style.background {
                         Background::Color(color) => css::color(color),
@@ -122,7 +122,8 @@ where
                     css::length(self.height),
                     style.border_width,
                     border_color,
-                    css::color(style.text_color)
+                    css::color(style.text_color),
+                    super::shadow::box_shadow(style.shadow.as_ref())
                 )
                 .into_bump_str(),
             )
@@ -3,11 +3,16 @@
 //! *This API requires the following crate features to be activated: card*
 use iced_web::{css, Background, Bus, Color, Css, Element, Length, Widget};
 use dodrio::bumpalo;
+use std::hash::{Hash, Hasher};
+use wasm_bindgen::JsCast;
 
 pub use crate::style::card::{Style, StyleSheet};
 
 const DEFAULT_PADDING: f32 = 10.0;
 
+/// The elements handling clicks on their own, which don't press the card.
+const INTERACTIVE_ELEMENTS: &str = "a, button, input, label, select, textarea";
+
 /// A card consisting of a head, body and optional foot.
 /// 
 /// TODO: Example
@@ -22,9 +27,20 @@ pub struct Card<'a, Message> {
     padding_foot: f32,
     close_size: Option<f32>,
     on_close: Option<Message>,
+    on_toggle: Option<Message>,
+    expanded: bool,
+    head_actions: Vec<(char, Message)>,
+    on_press: Option<Message>,
+    selected: bool,
+    scrollable: bool,
+    scrollbar_width: u16,
+    media_position: MediaPosition,
+    media_fit: MediaFit,
+    media_height: Option<u32>,
     head: Element<'a, Message>,
     body: Element<'a, Message>,
     foot: Option<Element<'a, Message>>,
+    media: Option<Element<'a, Message>>,
     style: Box<dyn StyleSheet>,
 }
 
@@ -46,9 +62,20 @@ impl<'a, Message> Card<'a, Message> {
             padding_foot: DEFAULT_PADDING,
             close_size: None,
             on_close: None,
+            on_toggle: None,
+            expanded: true,
+            head_actions: Vec::new(),
+            on_press: None,
+            selected: false,
+            scrollable: false,
+            scrollbar_width: 10,
+            media_position: MediaPosition::default(),
+            media_fit: MediaFit::default(),
+            media_height: None,
             head: head.into(),
             body: body.into(),
             foot: None,
+            media: None,
             style: Default::default(),
         }
     }
@@ -63,6 +90,43 @@ impl<'a, Message> Card<'a, Message> {
         self
     }
 
+    /// Sets the [`Element`](iced_web::Element) of the media of the
+    /// [`Card`](Card), like an image.
+    /// 
+    /// The media spans the width of the [`Card`](Card).
+    pub fn media<M>(mut self, media: M) -> Self
+    where
+        M: Into<Element<'a, Message>>
+    {
+        self.media = Some(media.into());
+        self
+    }
+
+    /// Sets the [`MediaPosition`](MediaPosition) of the media of the
+    /// [`Card`](Card).
+    pub fn media_position(mut self, position: MediaPosition) -> Self {
+        self.media_position = position;
+        self
+    }
+
+    /// Sets how the media is fitted into the media area of the
+    /// [`Card`](Card).
+    /// 
+    /// This only has an effect if the [`media_height`](Card::media_height)
+    /// is set.
+    pub fn media_fit(mut self, fit: MediaFit) -> Self {
+        self.media_fit = fit;
+        self
+    }
+
+    /// Sets the height of the media area of the [`Card`](Card).
+    /// 
+    /// By default, the media area is as high as the media.
+    pub fn media_height(mut self, height: u32) -> Self {
+        self.media_height = Some(height);
+        self
+    }
+
     /// Sets the width of the [`Card`](Card).
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -131,6 +195,77 @@ impl<'a, Message> Card<'a, Message> {
         self
     }
 
+    /// Sets the message that will be produced when the head or the chevron
+    /// icon of the [`Card`](Card) is pressed.
+    /// 
+    /// Setting this enables the drawing of a chevron icon on the
+    /// [`Card`](Card). You have to toggle the [`expanded`](Card::expanded)
+    /// flag by yourself.
+    pub fn on_toggle(mut self, msg: Message) -> Self {
+        self.on_toggle = Some(msg);
+        self
+    }
+
+    /// Adds an action button showing the given icon to the head of the
+    /// [`Card`](Card), producing the given message when it is pressed.
+    /// 
+    /// The actions are placed in the order they were added in front of the
+    /// chevron and the close icon. The icon is shown with the
+    /// `bootstrap-icons` font family, which has to be provided by the page.
+    pub fn head_action(mut self, icon: impl Into<char>, msg: Message) -> Self {
+        self.head_actions.push((icon.into(), msg));
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Card`](Card) is
+    /// clicked.
+    /// 
+    /// Clicks on the head actions, the chevron or the close icon do not
+    /// press the [`Card`](Card).
+    pub fn on_press(mut self, _state: &'a mut State, msg: Message) -> Self {
+        self.on_press = Some(msg);
+        self
+    }
+
+    /// Sets whether the [`Card`](Card) is selected.
+    /// 
+    /// A selected [`Card`](Card) is drawn with the
+    /// [`selected`](crate::style::card::StyleSheet::selected) appearance.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Makes the body of the [`Card`](Card) scrollable.
+    /// 
+    /// If the height of the [`Card`](Card) is constrained, for example by
+    /// [`max_height`](Card::max_height), the body shrinks to the space left
+    /// by the head and the foot and scrolls its content, while the head and
+    /// the foot stay in place.
+    pub fn scrollable(mut self, _state: &'a mut ScrollState) -> Self {
+        self.scrollable = true;
+        self
+    }
+
+    /// Sets the width of the scrollbar of a scrollable body of the
+    /// [`Card`](Card).
+    /// 
+    /// Browsers only distinguish between thin and normal scrollbars, so a
+    /// width below 10 results in a thin scrollbar.
+    pub fn scrollbar_width(mut self, width: u16) -> Self {
+        self.scrollbar_width = width;
+        self
+    }
+
+    /// Sets whether the [`Card`](Card) is expanded.
+    /// 
+    /// A collapsed [`Card`](Card) only shows its head. It is expanded by
+    /// default.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
     /// Sets the style of the [`Card`](Card).
     pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self
     {
@@ -151,13 +286,32 @@ where
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
-        let style = self.style.active();
+        // A selected card keeps its border in every state.
+        let selected = self.style.selected();
+        let with_selection = |style: Style| if self.selected {
+            Style {
+                border_width: selected.border_width,
+                border_color: selected.border_color,
+                .. style
+            }
+        } else {
+            style
+        };
+
+        let style = with_selection(self.style.active());
+
+        let media = self.media.as_ref().filter(|_| self.expanded);
+        let is_media_above_head = media.is_some()
+            && self.media_position == MediaPosition::AboveHead;
 
         let head_node = head_node(
             &self.head,
             self.padding_head,
             self.width,
+            is_media_above_head,
             &self.on_close,
+            &self.head_actions,
+            self.on_toggle.as_ref().map(|on_toggle| (on_toggle, self.expanded)),
             self.close_size,
             &style,
             bump,
@@ -165,27 +319,89 @@ where
             style_sheet,
         );
 
-        let body_node = body_node(
-            &self.body,
-            self.padding_body,
+        // A collapsed card hides its body and foot.
+        let body_node = if self.expanded {
+            Some(body_node(
+                &self.body,
+                self.padding_body,
+                self.width,
+                if self.scrollable { Some(self.scrollbar_width) } else { None },
+                &style,
+                bump,
+                bus,
+                style_sheet,
+            ))
+        } else {
+            None
+        };
+
+        let foot_node = self.foot.as_ref().filter(|_| self.expanded).map(|foot| foot_node(
+            foot,
+            self.padding_foot,
             self.width,
             &style,
             bump,
             bus,
             style_sheet,
-        );
+        ));
 
-        let foot_node = self.foot.as_ref().map(|foot| foot_node(
-            foot,
-            self.padding_foot,
-            self.width,
+        let media_node = media.map(|media| media_node(
+            media,
+            is_media_above_head,
+            self.media_fit,
+            self.media_height,
             &style,
             bump,
             bus,
             style_sheet,
         ));
 
+        // The states of a clickable card are styled by CSS rules scoped to a
+        // class derived from its appearance.
+        let press = self.on_press.as_ref().map(|on_press| {
+            let hovered = with_selection(self.style.hovered());
+            let pressed = with_selection(self.style.pressed());
+
+            let rules = bumpalo::format!(
+                in bump,
+                "{{class}}:hover {{ background: {} !important; }} \
+                {{class}}:hover > .iced_aw_card_border {{ border: {}px solid {} !important; }} \
+                {{class}}:active {{ background: {} !important; }} \
+                {{class}}:active > .iced_aw_card_border {{ border: {}px solid {} !important; }}",
+                match hovered.background {
+                    Background::Color(color) => css::color(color),
+                },
+                hovered.border_width,
+                css::color(hovered.border_color),
+                match pressed.background {
+                    Background::Color(color) => css::color(color),
+                },
+                pressed.border_width,
+                css::color(pressed.border_color)
+            );
+
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            rules.as_str().hash(&mut hasher);
+            let class = bumpalo::format!(in bump, "iced_aw_card_{:x}", hasher.finish())
+                .into_bump_str();
+
+            let rules = bumpalo::format!(
+                in bump,
+                "{}",
+                rules.replace("{class}", &format!(".{}", class))
+            );
+
+            (
+                class,
+                dodrio::builder::style(bump)
+                    .children(vec![text(rules.into_bump_str())])
+                    .finish(),
+                on_press.clone(),
+            )
+        });
+
         let border = div(bump)
+            .attr("class", "iced_aw_card_border")
             .attr(
                 "style",
                 bumpalo::format!(
@@ -200,13 +416,18 @@ where
             )
             .finish();
 
-        let node = div(bump)
+        let mut node = div(bump)
+            .attr(
+                "class",
+                press.as_ref().map_or("", |(class, _, _)| *class),
+            )
             .attr(
                 "style",
                 bumpalo::format!(
                     in bump,
                     "background: {}; border-radius: {}px; width: {}; height: {}; \
-                    position: relative; max-width: {}px; max-height: {}px;",
+                    position: relative; max-width: {}px; max-height: {}px; \
+                    cursor: {}; {} {}",
                     match style.background {
                         Background::Color(color) => css::color(color),
                     },
@@ -214,18 +435,49 @@ where
                     css::length(self.width),
                     css::length(self.height),
                     self.max_width,
-                    self.max_height
+                    self.max_height,
+                    if press.is_some() { "pointer" } else { "auto" },
+                    if self.scrollable {
+                        "display: flex; flex-direction: column;"
+                    } else {
+                        ""
+                    },
+                    super::shadow::box_shadow(style.shadow.as_ref())
                 )
                 .into_bump_str(),
-            )
-            .children(
-                match foot_node {
-                    Some(foot_node) => vec![head_node, body_node, foot_node, border],
-                    None => vec![head_node, body_node, border]
-                }
             );
 
-        node.finish()
+        let rules = match press {
+            Some((_, rules, on_press)) => {
+                let event_bus = bus.clone();
+
+                node = node.on("click", move |_root, _vdom, event| {
+                    // A click on a button in the body or foot only presses
+                    // the button.
+                    if !is_interactive_target(&event) {
+                        event_bus.publish(on_press.clone());
+                    }
+                });
+
+                Some(rules)
+            },
+            None => None,
+        };
+
+        node.children(
+            match self.media_position {
+                MediaPosition::AboveHead => vec![
+                    rules, media_node, Some(head_node), body_node, foot_node, Some(border)
+                ],
+                MediaPosition::BelowHead => vec![
+                    rules, Some(head_node), media_node, body_node, foot_node, Some(border)
+                ],
+            }
+                .into_iter()
+                .flatten()
+                .collect::<Vec<dodrio::Node<'b>>>()
+        )
+        .finish()
     }
 }
 
@@ -233,7 +485,10 @@ fn head_node<'a, 'b, Message: 'static + Clone>(
     my_head: &Element<'a, Message>,
     padding: f32,
     _width: Length,
+    is_media_above_head: bool,
     on_close: &Option<Message>,
+    head_actions: &[(char, Message)],
+    toggle: Option<(&Message, bool)>,
     close_size: Option<f32>,
     my_style: &Style,
     bump: &'b bumpalo::Bump,
@@ -274,43 +529,170 @@ fn head_node<'a, 'b, Message: 'static + Clone>(
                 .into_bump_str(),
             )
             .children(vec![text(close_icon(my_style.close_color, bump).into_bump_str())])
-            .on("click", move |_root, _vdom, _event| {
+            .on("click", move |_root, _vdom, event| {
+                // Do not toggle the card that is closed.
+                event.stop_propagation();
                 event_bus.publish(on_close.clone());
             })
             .finish()
     });
 
-    let my_head = div(bump)
+    let actions = head_actions.iter().map(|(icon, msg)| {
+        let event_bus = bus.clone();
+        let msg = msg.clone();
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "font-family: bootstrap-icons; font-size: {}px; width: auto; \
+                    height: auto; text-align: center; color: {}; cursor: pointer;",
+                    close_size.unwrap_or(20.0),
+                    css::color(my_style.head_text_color)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![text(bumpalo::format!(in bump, "{}", icon).into_bump_str())])
+            .on("click", move |_root, _vdom, event| {
+                // Do not toggle the card by pressing an action.
+                event.stop_propagation();
+                event_bus.publish(msg.clone());
+            })
+            .finish()
+    });
+
+    let chevron = toggle.map(|(_, expanded)| {
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "font-size: {}px; width: auto; height: auto; \
+                    text-align: center; color: {};",
+                    close_size.unwrap_or(20.0),
+                    css::color(my_style.head_text_color)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![text(if expanded { "\u{25b4}" } else { "\u{25be}" })])
+            .finish()
+    });
+
+    let mut my_head = div(bump)
         .attr("class", head_class)
         .attr(
             "style",
             bumpalo::format!(
                 in bump,
                 "background: {}; border-radius: {}px; width: 100%; \
-                color: {}; display: flex;",
+                color: {}; display: flex; cursor: {};",
                 match my_style.head_background {
                     Background::Color(color) => css::color(color),
                 },
-                my_style.border_radius,
-                css::color(my_style.head_text_color)
+                // The media takes over the rounded corners at the top.
+                if is_media_above_head { 0.0 } else { my_style.border_radius },
+                css::color(my_style.head_text_color),
+                if toggle.is_some() { "pointer" } else { "auto" }
             )
             .into_bump_str(),
         )
         .children(
-            match close {
-                Some(close) => vec![head_content, close],
-                None => vec![head_content]
-            }
-        )
+            std::iter::once(head_content)
+                .chain(actions)
+                .chain(chevron)
+                .chain(close)
+                .collect::<Vec<dodrio::Node<'b>>>()
+        );
+
+    if let Some((on_toggle, _)) = toggle {
+        let event_bus = bus.clone();
+        let on_toggle = on_toggle.clone();
+
+        my_head = my_head.on("click", move |_root, _vdom, event| {
+            // Do not press the card by toggling it.
+            event.stop_propagation();
+            event_bus.publish(on_toggle.clone());
+        });
+    }
+
+    my_head.finish()
+}
+
+fn media_node<'a, 'b, Message>(
+    my_media: &Element<'a, Message>,
+    is_above_head: bool,
+    fit: MediaFit,
+    height: Option<u32>,
+    my_style: &Style,
+    bump: &'b bumpalo::Bump,
+    bus: &Bus<Message>,
+    style_sheet: &mut Css<'b>,
+) -> dodrio::Node<'b> {
+    use dodrio::builder::*;
+
+    let fit_class = match fit {
+        MediaFit::Cover => "iced_aw_card_media_cover",
+        MediaFit::Contain => "iced_aw_card_media_contain",
+    };
+
+    // Let the media fill the media area as far as its fit allows.
+    let rules = dodrio::builder::style(bump)
+        .children(vec![text(
+            bumpalo::format!(
+                in bump,
+                ".{} > :not(style) {{ width: 100%; height: 100%; object-fit: {}; }}",
+                fit_class,
+                match fit {
+                    MediaFit::Cover => "cover",
+                    MediaFit::Contain => "contain",
+                }
+            )
+            .into_bump_str(),
+        )])
         .finish();
 
-    my_head
+    div(bump)
+        .attr("class", fit_class)
+        .attr(
+            "style",
+            bumpalo::format!(
+                in bump,
+                "background: {}; border-radius: {}; width: 100%; {} \
+                overflow: hidden; display: flex; align-items: center; \
+                justify-content: center;",
+                match my_style.media_background {
+                    Background::Color(color) => css::color(color),
+                },
+                if is_above_head {
+                    bumpalo::format!(
+                        in bump,
+                        "{0}px {0}px 0 0",
+                        my_style.border_radius
+                    ).into_bump_str()
+                } else {
+                    "0"
+                },
+                match height {
+                    Some(height) => bumpalo::format!(
+                        in bump,
+                        "height: {}px;",
+                        height
+                    ).into_bump_str(),
+                    None => "",
+                }
+            )
+            .into_bump_str(),
+        )
+        .children(vec![rules, my_media.node(bump, bus, style_sheet)])
+        .finish()
 }
 
 fn body_node<'a, 'b, Message>(
     my_body: &Element<'a, Message>,
     padding: f32,
     _width: Length,
+    scrollbar_width: Option<u16>,
     my_style: &Style,
     bump: &'b bumpalo::Bump,
     bus: &Bus<Message>,
@@ -333,11 +715,26 @@ fn body_node<'a, 'b, Message>(
             "style",
             bumpalo::format!(
                 in bump,
-                "background: {}; width: 100%; color: {};",
+                "background: {}; width: 100%; color: {}; {}",
                 match my_style.body_background {
                     Background::Color(color) => css::color(color),
                 },
-                css::color(my_style.body_text_color)
+                css::color(my_style.body_text_color),
+                // The scrollable body shrinks to the space left by the head
+                // and foot.
+                match scrollbar_width {
+                    Some(width) => bumpalo::format!(
+                        in bump,
+                        "flex: 1 1 auto; min-height: 0; overflow-y: auto; \
+                        scrollbar-width: {}; scrollbar-color: {} {};",
+                        if width < 10 { "thin" } else { "auto" },
+                        css::color(my_style.scroller_color),
+                        match my_style.scrollbar_background {
+                            Background::Color(color) => css::color(color),
+                        }
+                    ).into_bump_str(),
+                    None => "",
+                }
             )
             .into_bump_str(),
         )
@@ -389,6 +786,69 @@ fn foot_node<'a, 'b, Message>(
     foot_node
 }
 
+/// The position of the media of a [`Card`](Card).
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum MediaPosition {
+    /// The media is placed above the head.
+    AboveHead,
+
+    /// The media is placed between the head and the body.
+    BelowHead,
+}
+
+impl Default for MediaPosition {
+    fn default() -> Self {
+        MediaPosition::AboveHead
+    }
+}
+
+/// How the media of a [`Card`](Card) is fitted into its media area.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum MediaFit {
+    /// The media is scaled to cover the whole media area and the parts
+    /// sticking out are cut off.
+    Cover,
+
+    /// The media is scaled to fit into the media area, leaving the
+    /// [`media_background`](crate::style::card::Style::media_background)
+    /// visible around it.
+    Contain,
+}
+
+impl Default for MediaFit {
+    fn default() -> Self {
+        MediaFit::Cover
+    }
+}
+
+/// The state of the scrollable body of a [`Card`](Card).
+/// 
+/// The web version of the [`Card`](Card) does not need to keep any state.
+/// It only exists to share the same API with the native version.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScrollState;
+
+impl ScrollState {
+    /// Creates a new [`ScrollState`](ScrollState) of a [`Card`](Card).
+    pub fn new() -> Self {
+        ScrollState::default()
+    }
+}
+
+/// The state of a clickable [`Card`](Card).
+/// 
+/// The web version of the [`Card`](Card) does not need to keep any state.
+/// It only exists to share the same API with the native version.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`](State) of a [`Card`](Card).
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
 impl<'a, Message> From<Card<'a, Message>> for Element<'a, Message>
 where 
     Message: 'static + Clone,
@@ -398,6 +858,20 @@ where
     }
 }
 
+/// Returns true if the given event targets an interactive element inside of
+/// the element handling the event.
+fn is_interactive_target(event: &web_sys::Event) -> bool {
+    let current = event.current_target()
+        .and_then(|current| current.dyn_into::<web_sys::Node>().ok());
+
+    event.target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|target| target.closest(INTERACTIVE_ELEMENTS).ok().flatten())
+        .map_or(false, |interactive| {
+            current.map_or(false, |current| current.contains(Some(interactive.as_ref())))
+        })
+}
+
 // TODO: move this into own module
 /*fn close_icon<'b>(color: Color, bump: &'b bumpalo::Bump) -> dodrio::bumpalo::collections::String<'b> {
     use dodrio::bumpalo::collections::String;
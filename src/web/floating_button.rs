@@ -5,6 +5,7 @@ use iced_web::{Bus, Button, Css, Element, Widget};
 use dodrio::bumpalo;
 
 pub use crate::style::button::*;
+use crate::style::shadow::Shadow;
 
 pub mod anchor;
 pub use anchor::Anchor;
@@ -20,6 +21,8 @@ pub struct FloatingButton<'a, Message> {
     anchor: Anchor,
     offset: Offset,
     hidden: bool,
+    shadow: Option<Shadow>,
+    shadow_border_radius: f32,
     on_press: Option<Message>,
     underlay: Element<'a, Message>,
     button: Button<'a, Message>,
@@ -41,6 +44,8 @@ where
             anchor: Anchor::SouthEast,
             offset: 5.0.into(),
             hidden: false,
+            shadow: None,
+            shadow_border_radius: 0.0,
             on_press: None,
             underlay: underlay.into(),
             button: button.into(),
@@ -69,6 +74,23 @@ where
         self
     }
 
+    /// Sets the drop [`Shadow`](crate::style::shadow::Shadow) of the
+    /// [`Button`](iced_web::Button) on the [`FloatingButton`](FloatingButton).
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the border radius of the shadow of the
+    /// [`FloatingButton`](FloatingButton).
+    /// 
+    /// This should match the border radius of the style of the
+    /// [`Button`](iced_web::Button).
+    pub fn shadow_border_radius(mut self, border_radius: f32) -> Self {
+        self.shadow_border_radius = border_radius;
+        self
+    }
+
     /// Sets the `on_press` message for the [`Button`].
     /// 
     /// This is currently only a workaround.
@@ -107,8 +129,10 @@ where
                         "style",
                         bumpalo::format!(
                             in bump,
-                            "position: absolute; {}",
-                            position
+                            "position: absolute; {} border-radius: {}px; {}",
+                            position,
+                            self.shadow_border_radius,
+                            super::shadow::box_shadow(self.shadow.as_ref())
                         ).into_bump_str(),
                     )
                     .children(vec![self.button.node(bump, bus, style_sheet)])
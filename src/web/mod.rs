@@ -1,5 +1,13 @@
 //! Widgets for iced_web
 
+#[cfg(any(
+    feature = "badge",
+    feature = "card",
+    feature = "floating_button",
+    feature = "modal",
+))]
+pub(crate) mod shadow;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
@@ -10,6 +18,9 @@ pub mod card;
 #[cfg(feature = "card")]
 pub use card::Card;
 
+#[cfg(feature = "dialog")]
+pub mod dialog;
+
 #[cfg(feature = "floating_button")]
 pub mod floating_button;
 #[cfg(feature = "floating_button")]
@@ -18,4 +29,14 @@ pub use floating_button::FloatingButton;
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
-pub use modal::Modal;
\ No newline at end of file
+pub use modal::Modal;
+
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar;
+#[cfg(feature = "tab_bar")]
+pub use tab_bar::{TabBadge, TabBar, TabLabel};
+
+#[cfg(feature = "tabs")]
+pub mod tabs;
+#[cfg(feature = "tabs")]
+pub use tabs::{TabBarPosition, Tabs};
\ No newline at end of file
@@ -1,8 +1,11 @@
 //! A modal for showing elements as an overlay on top of another.
 //! 
 //! *This API requires the following crate features to be activated: modal*
+use std::time::Duration;
+
 use iced_web::{css, Background, Bus, Css, Element, Widget};
 use dodrio::bumpalo;
+use wasm_bindgen::JsCast;
 
 use crate::style::modal::StyleSheet;
 
@@ -11,6 +14,19 @@ use crate::style::modal::StyleSheet;
 /// Can be used in combination with the [`Card`](crate::native::card::Card)
 /// widget to form dialog elements.
 /// 
+/// Modals can be stacked by using a [`Modal`](Modal) as the underlay of
+/// another one. The outer [`Modal`](Modal) is shown on top of the inner one
+/// and dims it with its backdrop, so only the topmost backdrop can be
+/// clicked.
+/// 
+/// While the [`Modal`](Modal) is shown, its underlay is inert, so it can
+/// neither be focused nor receive any input. The browser cycles the focus
+/// through the content with the Tab key.
+/// 
+/// With an [`animation`](Modal::animation), the backdrop fades in while the
+/// content slides in when the [`Modal`](Modal) is opened and both are
+/// reversed by CSS transitions when it is closed.
+/// 
 /// TODO: Example
 #[allow(missing_debug_implementations)]
 pub struct Modal<'a, Message>
@@ -22,6 +38,9 @@ where
     content: Element<'a, Message>,
     backdrop: Option<Message>,
     esc: Option<Message>,
+    trap_focus: bool,
+    placement: Placement,
+    animation: Option<Animation>,
     style: Box<dyn StyleSheet>,
 }
 
@@ -55,6 +74,9 @@ where
             content: content(state),
             backdrop: None,
             esc: None,
+            trap_focus: false,
+            placement: Placement::default(),
+            animation: None,
             style: Default::default(),
         }
     }
@@ -69,12 +91,52 @@ where
     /// Sets the message that will be produced when the Escape Key is
     /// pressed when the modal is open.
     /// 
-    /// This can be used to close the modal on ESC.
+    /// This can be used to close the modal on ESC. The browser only sends the
+    /// key to the [`Modal`](Modal) while the focus is inside of it, which is
+    /// the case after clicking it or moving the focus into it with the Tab
+    /// key. Only the topmost [`Modal`](Modal) of a stack handles the key.
     pub fn on_esc(mut self, message: Message) -> Self {
         self.esc = Some(message);
         self
     }
 
+    /// Sets whether the focus of the underlay is restored when the
+    /// [`Modal`](Modal) is closed.
+    /// 
+    /// The browser keeps track of the focus by itself, so this only exists
+    /// to share the same API with the native version.
+    pub fn restore_focus(self, _restore: bool) -> Self {
+        self
+    }
+
+    /// Traps the focus inside of the content of the [`Modal`](Modal).
+    /// 
+    /// The underlay is always inert while the [`Modal`](Modal) is shown.
+    /// This additionally marks the content as a modal dialog for assistive
+    /// technologies.
+    pub fn trap_focus(mut self) -> Self {
+        self.trap_focus = true;
+        self
+    }
+
+    /// Sets the [`Placement`](Placement) of the content of the
+    /// [`Modal`](Modal), like a bottom sheet or a side drawer.
+    /// 
+    /// The content is centered by default.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the [`Animation`](Animation) of opening and closing the
+    /// [`Modal`](Modal).
+    /// 
+    /// The [`Modal`](Modal) appears and disappears instantly by default.
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
     /// Sets the style of the [`Modal`](Modal).
     pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
         self.style = style.into();
@@ -82,6 +144,119 @@ where
     }
 }
 
+/// The placement of the content of a [`Modal`](Modal) in the window.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum Placement {
+    /// The content keeps its size and is centered in the window.
+    Center,
+
+    /// The content keeps its size and drops down from the top edge of the
+    /// window, centered horizontally.
+    Top,
+
+    /// The content is placed as a sheet at the bottom edge of the window,
+    /// filling its width.
+    Bottom,
+
+    /// The content is placed as a drawer at the left edge of the window,
+    /// filling its height.
+    Left,
+
+    /// The content is placed as a drawer at the right edge of the window,
+    /// filling its height.
+    Right,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement::Center
+    }
+}
+
+/// The [`Animation`](Animation) of opening and closing a [`Modal`](Modal).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animation {
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`](Animation) taking the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Animation {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`](Easing) of the [`Animation`](Animation).
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the CSS transition of the given property running this
+    /// [`Animation`](Animation).
+    fn transition(&self, property: &str) -> String {
+        format!(
+            "{} {}ms {}",
+            property,
+            self.duration.as_millis(),
+            match self.easing {
+                Easing::Linear => "linear",
+                Easing::EaseIn => "ease-in",
+                Easing::EaseOut => "ease-out",
+                Easing::EaseInOut => "ease-in-out",
+            },
+        )
+    }
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Animation::new(Duration::from_millis(200))
+    }
+}
+
+/// The easing function of an [`Animation`](Animation) mapping the elapsed
+/// time to its progress.
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum Easing {
+    /// The progress increases at a constant speed.
+    Linear,
+
+    /// The progress starts slowly and speeds up.
+    EaseIn,
+
+    /// The progress starts quickly and slows down.
+    EaseOut,
+
+    /// The progress starts slowly, speeds up and slows down again.
+    EaseInOut,
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::EaseOut
+    }
+}
+
+/// The state of the content of a [`Modal`](Modal) holding focusable
+/// widgets, like the states of text inputs.
+/// 
+/// The browser cycles the focus by itself, so this only exists to share the
+/// same API with the native version.
+pub trait Focus {
+    /// Returns the number of focusable widgets of the content.
+    fn focusable_count(&self) -> usize;
+
+    /// Returns the index of the focused widget of the content, if any.
+    fn focused(&self) -> Option<usize>;
+
+    /// Focuses the widget with the given index and unfocuses all others.
+    fn focus(&mut self, index: usize);
+}
+
 /// The state of the modal.
 #[derive(Debug)]
 pub struct State<S> {
@@ -120,9 +295,16 @@ where
 
         let style = self.style.active();
 
-        let underlay = self.underlay.node(bump, bus, style_sheet);
+        // The wrapper does not take part in the layout of the underlay.
+        let underlay = div(bump)
+            .attr("style", "display: contents;")
+            .bool_attr("inert", self.show)
+            .children(vec![self.underlay.node(bump, bus, style_sheet)])
+            .finish();
 
-        let modal = if self.show {
+        // An animated modal stays in the document while it is hidden, so
+        // the transitions can run in both directions.
+        let modal = if self.show || self.animation.is_some() {
             let event_bus = bus.clone();
 
             let mut backdrop = div(bump)
@@ -130,10 +312,15 @@ where
                     "style",
                     bumpalo::format!(
                         in bump,
-                        "position: absolute; background: {}; width: 100%; height: 100%;",
+                        "position: absolute; background: {}; width: 100%; height: 100%; \
+                        opacity: {}; transition: {};",
                         match style.background {
                             Background::Color(color) => css::color(color),
-                        }
+                        },
+                        if self.show { 1 } else { 0 },
+                        self.animation.map_or(String::from("none"), |animation| {
+                            animation.transition("opacity")
+                        }),
                     ).into_bump_str(),
                 );
 
@@ -145,24 +332,106 @@ where
 
             let backdrop = backdrop.finish();
 
-            let modal_content = div(bump)
+            let mut modal_content = div(bump);
+
+            if self.trap_focus {
+                modal_content = modal_content
+                    .attr("role", "dialog")
+                    .attr("aria-modal", "true");
+            }
+
+            // Bottom sheets and side drawers stretch their content along
+            // the edge they are attached to. The content slides in from this
+            // edge, centered content slides up by a quarter of its height.
+            let (placement, shown, hidden) = match self.placement {
+                Placement::Center => (
+                    "top: 50%; left: 50%;",
+                    "translate(-50%, -50%)",
+                    "translate(-50%, -25%)",
+                ),
+                Placement::Top => (
+                    "top: 0; left: 50%;",
+                    "translateX(-50%)",
+                    "translate(-50%, -100%)",
+                ),
+                Placement::Bottom => (
+                    "bottom: 0; left: 0; right: 0; \
+                    display: flex; flex-direction: column;",
+                    "none",
+                    "translateY(100%)",
+                ),
+                Placement::Left => (
+                    "top: 0; bottom: 0; left: 0; display: flex;",
+                    "none",
+                    "translateX(-100%)",
+                ),
+                Placement::Right => (
+                    "top: 0; bottom: 0; right: 0; display: flex;",
+                    "none",
+                    "translateX(100%)",
+                ),
+            };
+
+            let modal_content = modal_content
                 .attr(
                     "style",
-                    "margin: auto; position: absolute; top: 50%;\
-                    transform: translate(-50%, -50%); left: 50%;"
+                    bumpalo::format!(
+                        in bump,
+                        "margin: auto; position: absolute; {} transform: {}; \
+                        transition: {}; border-radius: {}px; {}",
+                        placement,
+                        if self.show { shown } else { hidden },
+                        self.animation.map_or(String::from("none"), |animation| {
+                            animation.transition("transform")
+                        }),
+                        style.content_border_radius,
+                        super::shadow::box_shadow(style.content_shadow.as_ref())
+                    ).into_bump_str()
                 )
                 .children(vec![self.content.node(bump, bus, style_sheet)])
                 .finish();
 
-            Some(div(bump)
+            // The modal is placed above everything in the stacking context of
+            // its wrapper, including the modals wrapped by it. A closing modal
+            // ignores the pointer and is only made invisible after its close
+            // transition finished.
+            let mut modal = div(bump)
+                .attr("tabindex", "-1")
                 .attr(
                     "style",
-                    "position: absolute; top: 0; bottom: 0; left: 0; right: 0;"
+                    bumpalo::format!(
+                        in bump,
+                        "position: absolute; top: 0; bottom: 0; left: 0; right: 0; \
+                        z-index: 1; visibility: {}; pointer-events: {}; \
+                        transition: visibility 0s {}ms; outline: none;",
+                        if self.show { "visible" } else { "hidden" },
+                        if self.show { "auto" } else { "none" },
+                        match self.animation {
+                            Some(animation) if !self.show => animation.duration.as_millis(),
+                            _ => 0,
+                        },
+                    ).into_bump_str()
                 ).children(
                     vec![ backdrop,  modal_content ]
-                )
-                .finish()
-            )
+                );
+
+            // The underlay is inert, so the focus is inside of the topmost
+            // modal and the key does not reach the modals below it.
+            if let (true, Some(on_esc)) = (self.show, self.esc.clone()) {
+                let event_bus = bus.clone();
+
+                modal = modal.on("keydown", move |_root, _vdom, event| {
+                    let is_escape = event.dyn_ref::<web_sys::KeyboardEvent>()
+                        .map_or(false, |event| event.key() == "Escape");
+
+                    if is_escape {
+                        event.stop_propagation();
+                        event_bus.publish(on_esc.clone());
+                    }
+                });
+            }
+
+            Some(modal.finish())
         } else {
             None
         };
@@ -170,7 +439,7 @@ where
         div(bump)
             .attr(
                 "style",
-                "position: relative;"
+                "position: relative; z-index: 0;"
             )
             .children(
                 match modal {
@@ -0,0 +1,19 @@
+//! Styles the drop [`Shadow`](Shadow) of a widget.
+use iced_web::css;
+
+use crate::style::shadow::Shadow;
+
+/// Returns the CSS `box-shadow` declaration of the given optional
+/// [`Shadow`](Shadow).
+pub(crate) fn box_shadow(shadow: Option<&Shadow>) -> String {
+    match shadow {
+        Some(shadow) => format!(
+            "box-shadow: {}px {}px {}px {};",
+            shadow.offset_x,
+            shadow.offset_y,
+            shadow.blur_radius,
+            css::color(shadow.color),
+        ),
+        None => String::from("box-shadow: none;"),
+    }
+}
@@ -0,0 +1,1168 @@
+//! Displays a [`TabBar`](TabBar) to select the content to be displayed.
+//! 
+//! You have to manage the logic to show the contend by yourself or you may want
+//! to use the [`Tabs`](super::tabs::Tabs) widget instead.
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+use std::{collections::HashSet, rc::Rc};
+
+use iced_web::{css, Background, Bus, Color, Css, Element, Font, Length, Widget};
+use dodrio::bumpalo;
+use wasm_bindgen::JsCast;
+
+pub mod orientation;
+pub use orientation::Orientation;
+
+pub mod tab;
+pub use tab::Tab;
+
+pub mod tab_badge;
+pub use tab_badge::TabBadge;
+
+pub mod tab_label;
+pub use tab_label::TabLabel;
+
+pub use crate::style::tab_bar::{IndicatorPosition, Style, StyleSheet};
+
+const DEFAULT_ICON_SIZE: u16 = 32;
+const DEFAULT_TEXT_SIZE: u16 = 16;
+const DEFAULT_CLOSE_SIZE: u16 = 16;
+const DEFAULT_PADDING: u16 = 5;
+const DEFAULT_SPACING: u16 = 0;
+const BADGE_TEXT_SIZE: u16 = 12;
+
+/// The data type of the index of a dragged tab.
+const DRAG_DATA_TYPE: &str = "application/x-iced-aw-tab";
+
+/// The CSS rules replacing the modified indicator of a closable tab with its
+/// close icon while the tab is hovered.
+const MODIFIED_RULES: &str = ".iced_aw_tab_bar_modified > .iced_aw_tab_bar_close { display: none; } \
+    .iced_aw_tab_bar_tab:hover .iced_aw_tab_bar_modified > .iced_aw_tab_bar_marker { display: none; } \
+    .iced_aw_tab_bar_tab:hover .iced_aw_tab_bar_modified > .iced_aw_tab_bar_close { display: inline; }";
+
+/// A tab bar to show tabs.
+/// 
+/// The web version differs from the native [`TabBar`](TabBar) in a few
+/// points:
+/// - The tabs can't be docked to other [`TabBar`](TabBar)s, so there is no
+///   `dock` builder.
+/// - The tabs are reordered by the drag and drop of the browser, which
+///   neither draws a drop indicator nor moves the other tabs aside.
+/// - The [`overflow_menu`](TabBar::overflow_menu) is a drop down list.
+/// - The full text of a label is shown as the title of its tab, even if it
+///   isn't truncated.
+/// - The keyboard shortcuts only work while the [`TabBar`](TabBar) is
+///   focused.
+/// 
+/// TODO: Example
+#[allow(missing_debug_implementations)]
+pub struct TabBar<'a, Message> {
+    active_tab: usize,
+    tabs: Vec<Tab<'a, Message>>,
+    disabled_tabs: HashSet<usize>,
+    modified_tabs: HashSet<usize>,
+    pinned_tabs: HashSet<usize>,
+    on_select: Box<dyn Fn(usize) -> Message>,
+    on_close: Option<Box<dyn Fn(usize) -> Message>>,
+    on_reorder: Option<Rc<dyn Fn(usize, usize) -> Message>>,
+    on_new: Option<Message>,
+    overflow_menu: bool,
+    cycle_shortcuts: bool,
+    number_shortcuts: bool,
+    close_shortcut: bool,
+    arrow_navigation: bool,
+    width: Length,
+    tab_width: Length,
+    min_tab_width: Option<u16>,
+    max_tab_width: Option<u16>,
+    height: Length,
+    max_height: u32,
+    icon_size: u16,
+    text_size: u16,
+    close_size: u16,
+    padding: u16,
+    spacing: u16,
+    icon_font: Option<Font>,
+    text_font: Option<Font>,
+    orientation: Orientation,
+    style: Box<dyn StyleSheet>,
+}
+
+impl<'a, Message> TabBar<'a, Message> {
+    /// Creates a new [`TabBar`](TabBar) with the given [`State`](State), the
+    /// index of the selected tab and a specified message which will be send
+    /// when a tab is selected by the user.
+    pub fn new<F>(state: &'a mut State, active_tab: usize, on_select: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        Self::width_tab_labels(state, active_tab, Vec::new(), on_select)
+    }
+
+    /// Similar to `new` but with a given Vector of the
+    /// [`TabLabel`](tab_label::TabLabel)s.
+    pub fn width_tab_labels<F>(
+        _state: &'a mut State,
+        active_tab: usize,
+        tab_labels: Vec<TabLabel>,
+        on_select: F
+    ) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        TabBar {
+            active_tab,
+            tabs: tab_labels.into_iter().map(Tab::Label).collect(),
+            disabled_tabs: HashSet::new(),
+            modified_tabs: HashSet::new(),
+            pinned_tabs: HashSet::new(),
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            on_new: None,
+            overflow_menu: false,
+            cycle_shortcuts: true,
+            number_shortcuts: true,
+            close_shortcut: true,
+            arrow_navigation: true,
+            width: Length::Fill,
+            tab_width: Length::Fill,
+            min_tab_width: None,
+            max_tab_width: None,
+            height: Length::Shrink,
+            max_height: u32::MAX,
+            icon_size: DEFAULT_ICON_SIZE,
+            text_size: DEFAULT_TEXT_SIZE,
+            close_size: DEFAULT_CLOSE_SIZE,
+            padding: DEFAULT_PADDING,
+            spacing: DEFAULT_SPACING,
+            icon_font: None,
+            text_font: None,
+            orientation: Orientation::Horizontal,
+            style: Default::default(),
+        }
+    }
+
+    /// Gets the number of tabs on the [`TabBar`](TabBar).
+    pub fn tab_count(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Gets the index of the currently active tab on the [`TabBar`](TabBar).
+    pub fn get_active_tab(&self) -> usize {
+        self.active_tab
+    }
+
+    /// Sets whether the tab at the given index of the [`TabBar`](TabBar) is
+    /// disabled.
+    /// 
+    /// A disabled tab can neither be selected nor closed by the user.
+    pub fn tab_disabled(mut self, index: usize, disabled: bool) -> Self {
+        if disabled {
+            let _ = self.disabled_tabs.insert(index);
+        } else {
+            let _ = self.disabled_tabs.remove(&index);
+        }
+        self
+    }
+
+    /// Returns true if the tab at the given index of the [`TabBar`](TabBar)
+    /// is disabled.
+    pub fn is_tab_disabled(&self, index: usize) -> bool {
+        self.disabled_tabs.contains(&index)
+    }
+
+    /// Sets whether the tab at the given index of the [`TabBar`](TabBar) is
+    /// marked as modified.
+    /// 
+    /// A modified tab shows an indicator in place of its close icon. The
+    /// indicator still closes the tab when it is pressed.
+    pub fn tab_modified(mut self, index: usize, modified: bool) -> Self {
+        if modified {
+            let _ = self.modified_tabs.insert(index);
+        } else {
+            let _ = self.modified_tabs.remove(&index);
+        }
+        self
+    }
+
+    /// Returns true if the tab at the given index of the [`TabBar`](TabBar)
+    /// is marked as modified.
+    pub fn is_tab_modified(&self, index: usize) -> bool {
+        self.modified_tabs.contains(&index)
+    }
+
+    /// Sets whether the tab at the given index of the [`TabBar`](TabBar) is
+    /// pinned.
+    /// 
+    /// A pinned tab only shows the icon of its label and is placed in front
+    /// of the unpinned tabs, where it keeps its position while the tabs are
+    /// scrolled. It can't be closed by the user.
+    pub fn tab_pinned(mut self, index: usize, pinned: bool) -> Self {
+        if pinned {
+            let _ = self.pinned_tabs.insert(index);
+        } else {
+            let _ = self.pinned_tabs.remove(&index);
+        }
+        self
+    }
+
+    /// Returns true if the tab at the given index of the [`TabBar`](TabBar)
+    /// is pinned.
+    pub fn is_tab_pinned(&self, index: usize) -> bool {
+        self.pinned_tabs.contains(&index)
+    }
+
+    /// Sets the message that will be produced when the close icon of a tab
+    /// on the [`TabBar`](TabBar) is pressed.
+    /// 
+    /// Setting this enables the drawing of a close icon on the tabs.
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that will be produced when a tab of the
+    /// [`TabBar`](TabBar) is dragged to a new position.
+    /// 
+    /// The message is created from the index the tab was dragged from and
+    /// the index the tab should have after removing it from its old position.
+    /// 
+    /// Setting this enables the reordering of the tabs by drag and drop. A
+    /// tab dropped on another tab takes its position.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder = Some(Rc::new(on_reorder));
+        self
+    }
+
+    /// Sets the message that will be produced when the button for creating
+    /// a new tab on the [`TabBar`](TabBar) is pressed.
+    /// 
+    /// Setting this enables the drawing of the button behind the tabs.
+    pub fn on_new(mut self, on_new: Message) -> Self {
+        self.on_new = Some(on_new);
+        self
+    }
+
+    /// Sets whether the [`TabBar`](TabBar) shows a menu that lists all
+    /// tabs, including those scrolled out of view.
+    /// 
+    /// On the web, the menu is a drop down list behind the tabs.
+    pub fn overflow_menu(mut self, enabled: bool) -> Self {
+        self.overflow_menu = enabled;
+        self
+    }
+
+    /// Sets the width of the [`TabBar`](TabBar).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Gets the width of the [`TabBar`](TabBar).
+    pub fn get_width(&self) -> Length {
+        self.width
+    }
+
+    /// Sets the width of a tab on the [`TabBar`](TabBar).
+    /// 
+    /// On a horizontal [`TabBar`](TabBar), [`Length::Shrink`](Length::Shrink)
+    /// fits each tab to its content, while [`Length::Fill`](Length::Fill)
+    /// gives all tabs the same width. The tabs shrink down to the
+    /// [`min_tab_width`](TabBar::min_tab_width) before they start to
+    /// overflow. Labels that don't fit into their tab are truncated with an
+    /// ellipsis.
+    /// 
+    /// On the web, the full text of a label is shown by the browser when its
+    /// tab is hovered, even if the label isn't truncated.
+    pub fn tab_width(mut self, width: Length) -> Self {
+        self.tab_width = width;
+        self
+    }
+
+    /// Sets the minimum width of each tab on a horizontal
+    /// [`TabBar`](TabBar).
+    /// 
+    /// If it is not set, tabs of the width [`Length::Fill`](Length::Fill)
+    /// don't shrink below the width of their content.
+    pub fn min_tab_width(mut self, min_tab_width: u16) -> Self {
+        self.min_tab_width = Some(min_tab_width);
+        self
+    }
+
+    /// Sets the maximum width of each tab on a horizontal
+    /// [`TabBar`](TabBar).
+    pub fn max_tab_width(mut self, max_tab_width: u16) -> Self {
+        self.max_tab_width = Some(max_tab_width);
+        self
+    }
+
+    /// Sets the height of the [`TabBar`](TabBar).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Gets the width of the [`TabBar`](TabBar).
+    pub fn get_height(&self) -> Length {
+        self.height
+    }
+
+    /// Sets the maximum height of the [`TabBar`](TabBar).
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the icon size of the [`TabLabel`](tab_label::TabLabel)s of
+    /// the [`TabBar`](TabBar).
+    pub fn icon_size(mut self, icon_size: u16) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the text size of the [`TabLabel`](tab_label::TabLabel)s of the
+    /// [`TabBar`](TabBar).
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the size of the close icon of the
+    /// [`TabLabel`](tab_label::TabLabel)s of the [`TabBar`](TabBar).
+    pub fn close_size(mut self, close_size: u16) -> Self {
+        self.close_size = close_size;
+        self
+    }
+
+    /// Sets the padding of the tabs of the [`TabBar`](TabBar).
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`TabBar`](TabBar).
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the font of the icons of the
+    /// [`TabLabel`](tab_label::TabLabel)s of the [`TabBar`](TabBar).
+    pub fn icon_font(mut self, icon_font: Font) -> Self {
+        self.icon_font = Some(icon_font);
+        self
+    }
+
+    /// Sets the font of the text of the
+    /// [`TabLabel`](tab_label::TabLabel)s of the [`TabBar`](TabBar).
+    pub fn text_font(mut self, text_font: Font) -> Self {
+        self.text_font = Some(text_font);
+        self
+    }
+
+    /// Sets the [`Orientation`](Orientation) of the [`TabBar`](TabBar).
+    /// 
+    /// A vertical [`TabBar`](TabBar) places its tabs below each other.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets whether the tabs of the [`TabBar`](TabBar) can be cycled with
+    /// `Ctrl+Tab` and `Ctrl+Shift+Tab` while it is focused.
+    /// 
+    /// Most browsers reserve this shortcut for their own tabs, so it only
+    /// reaches the [`TabBar`](TabBar) of an installed web app.
+    /// 
+    /// This is enabled by default.
+    pub fn cycle_shortcuts(mut self, enabled: bool) -> Self {
+        self.cycle_shortcuts = enabled;
+        self
+    }
+
+    /// Sets whether the first eight tabs of the [`TabBar`](TabBar) can be
+    /// selected with `Ctrl+1` to `Ctrl+8` and the last tab with `Ctrl+9`
+    /// while it is focused.
+    /// 
+    /// Most browsers reserve these shortcuts for their own tabs, so they only
+    /// reach the [`TabBar`](TabBar) of an installed web app.
+    /// 
+    /// This is enabled by default.
+    pub fn number_shortcuts(mut self, enabled: bool) -> Self {
+        self.number_shortcuts = enabled;
+        self
+    }
+
+    /// Sets whether the active tab of the [`TabBar`](TabBar) can be closed
+    /// with `Ctrl+W` while it is focused. This only has an effect if
+    /// [`on_close`](TabBar::on_close) is set.
+    /// 
+    /// Most browsers reserve this shortcut for closing their own tabs, so it
+    /// only reaches the [`TabBar`](TabBar) of an installed web app.
+    /// 
+    /// This is enabled by default.
+    pub fn close_shortcut(mut self, enabled: bool) -> Self {
+        self.close_shortcut = enabled;
+        self
+    }
+
+    /// Sets whether the arrow keys select the previous or next tab while the
+    /// [`TabBar`](TabBar) is focused. The [`TabBar`](TabBar) gets focused by
+    /// clicking on it or by moving the keyboard focus to it.
+    /// 
+    /// This is enabled by default.
+    pub fn arrow_navigation(mut self, enabled: bool) -> Self {
+        self.arrow_navigation = enabled;
+        self
+    }
+
+    /// Sets the style of the [`TabBar`](TabBar).
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Pushes a [`TabLabel`](tab_label::TabLabel) to the [`TabBar`](TabBar).
+    pub fn push(mut self, tab_label: TabLabel) -> Self {
+        self.tabs.push(Tab::Label(tab_label));
+        self
+    }
+
+    /// Pushes an arbitrary [`Element`](iced_web::Element) as the label of
+    /// a tab to the [`TabBar`](TabBar).
+    pub fn push_element<E>(mut self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message>>,
+    {
+        self.tabs.push(Tab::Element(element.into()));
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for TabBar<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let style = self.style.active(false);
+
+        let (pinned, unpinned): (Vec<(usize, &Tab<'a, Message>)>, Vec<_>) = self.tabs.iter()
+            .enumerate()
+            .partition(|(index, _)| self.is_tab_pinned(*index));
+
+        let mut children: Vec<dodrio::Node<'b>> = Vec::with_capacity(self.tabs.len() + 3);
+
+        if self.modified_tabs.iter().any(|index| *index < self.tabs.len() && self.is_tab_closable(*index)) {
+            children.push(
+                dodrio::builder::style(bump)
+                    .children(vec![text(MODIFIED_RULES)])
+                    .finish()
+            );
+        }
+
+        // The pinned tabs stick to the start of the TabBar while it scrolls.
+        if !pinned.is_empty() {
+            let pinned: Vec<dodrio::Node<'b>> = pinned.into_iter()
+                .map(|(index, tab)| self.tab_node(index, tab, bump, bus, style_sheet))
+                .collect();
+
+            children.push(
+                div(bump)
+                    .attr(
+                        "style",
+                        bumpalo::format!(
+                            in bump,
+                            "display: flex; flex-direction: {}; flex: 0 0 auto; gap: {}px; \
+                            position: sticky; {}: 0; z-index: 1; background: {};",
+                            match self.orientation {
+                                Orientation::Horizontal => "row",
+                                Orientation::Vertical => "column",
+                            },
+                            self.spacing,
+                            match self.orientation {
+                                Orientation::Horizontal => "left",
+                                Orientation::Vertical => "top",
+                            },
+                            style.background.map_or(
+                                String::from("inherit"),
+                                |background| match background {
+                                    Background::Color(color) => css::color(color),
+                                }
+                            )
+                        )
+                        .into_bump_str(),
+                    )
+                    .children(pinned)
+                    .finish()
+            );
+        }
+
+        children.extend(
+            unpinned.into_iter()
+                .map(|(index, tab)| self.tab_node(index, tab, bump, bus, style_sheet))
+        );
+
+        if let Some(on_new) = self.on_new.clone() {
+            let event_bus = bus.clone();
+
+            children.push(
+                div(bump)
+                    .attr(
+                        "style",
+                        bumpalo::format!(
+                            in bump,
+                            "display: flex; align-items: center; justify-content: center; \
+                            padding: {}px; font-size: {}px; color: {}; cursor: pointer;",
+                            self.padding,
+                            self.close_size,
+                            css::color(style.icon_color)
+                        )
+                        .into_bump_str(),
+                    )
+                    .children(vec![text("+")])
+                    .on("click", move |_root, _vdom, _event| {
+                        event_bus.publish(on_new.clone());
+                    })
+                    .finish()
+            );
+        }
+
+        if self.overflow_menu && !self.tabs.is_empty() {
+            children.push(self.menu_node(&style, bump, bus));
+        }
+
+        let mut node = div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: flex; flex-direction: {}; width: {}; height: {}; \
+                    max-height: {}; overflow: auto; box-sizing: border-box; gap: {}px; \
+                    background: {}; border: {}px solid {}; border-radius: {}px;",
+                    match self.orientation {
+                        Orientation::Horizontal => "row",
+                        Orientation::Vertical => "column",
+                    },
+                    css::length(self.width),
+                    css::length(self.height),
+                    css::max_length(self.max_height),
+                    self.spacing,
+                    style.background.map_or(
+                        String::from("none"),
+                        |background| match background {
+                            Background::Color(color) => css::color(color),
+                        }
+                    ),
+                    style.border_width,
+                    style.border_color.map_or(
+                        String::from("transparent"),
+                        css::color,
+                    ),
+                    style.border_radius
+                )
+                .into_bump_str(),
+            )
+            .children(children);
+
+        let has_keyboard = self.cycle_shortcuts
+            || self.number_shortcuts
+            || self.close_shortcut
+            || self.arrow_navigation;
+
+        if has_keyboard && !self.tabs.is_empty() {
+            // The TabBar has to be focusable to receive the keyboard events.
+            node = node
+                .attr("tabindex", "0")
+                .on("keydown", self.keyboard_handler(bus));
+        }
+
+        node.finish()
+    }
+}
+
+impl<'a, Message> TabBar<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    /// Returns true if the tab at the given index can be closed by the user.
+    fn is_tab_closable(&self, index: usize) -> bool {
+        self.on_close.is_some() && !self.is_tab_disabled(index) && !self.is_tab_pinned(index)
+    }
+
+    /// Returns the indices of the tabs in the order they are displayed, with
+    /// the pinned tabs in front of the others.
+    fn display_order(&self) -> Vec<usize> {
+        let (pinned, unpinned): (Vec<usize>, Vec<usize>) = (0..self.tabs.len())
+            .partition(|index| self.is_tab_pinned(*index));
+
+        pinned.into_iter().chain(unpinned).collect()
+    }
+
+    /// Returns the index of the enabled tab displayed in front of or behind
+    /// the active tab, wrapping around at the ends if `wrap` is true.
+    fn next_tab(&self, forward: bool, wrap: bool) -> Option<usize> {
+        let order = self.display_order();
+        let len = order.len();
+        let from = order.iter().position(|index| *index == self.active_tab).unwrap_or(len);
+
+        let mut candidates: Box<dyn Iterator<Item = usize>> = match (forward, wrap) {
+            (true, false) => Box::new(from + 1..len),
+            (true, true) => Box::new((from + 1..len).chain(0..from)),
+            (false, false) => Box::new((0..from).rev()),
+            (false, true) => Box::new((0..from).rev().chain((from + 1..len).rev())),
+        };
+
+        candidates
+            .map(|position| order[position])
+            .find(|index| !self.is_tab_disabled(*index))
+    }
+
+    /// Creates the handler of the keyboard shortcuts of the
+    /// [`TabBar`](TabBar).
+    /// 
+    /// The messages of all shortcuts are created up front, as the handler
+    /// outlives the [`TabBar`](TabBar).
+    fn keyboard_handler(
+        &self,
+        bus: &Bus<Message>,
+    ) -> impl Fn(&mut dyn dodrio::RootRender, dodrio::VdomWeak, web_sys::Event) + 'static {
+        let event_bus = bus.clone();
+        let select = |index: Option<usize>| index.map(|index| (self.on_select)(index));
+        let order = self.display_order();
+
+        let (back_key, forward_key) = match self.orientation {
+            Orientation::Horizontal => ("ArrowLeft", "ArrowRight"),
+            Orientation::Vertical => ("ArrowUp", "ArrowDown"),
+        };
+        let (back, forward) = if self.arrow_navigation {
+            (select(self.next_tab(false, false)), select(self.next_tab(true, false)))
+        } else {
+            (None, None)
+        };
+        let (cycle_back, cycle_forward) = if self.cycle_shortcuts {
+            (select(self.next_tab(false, true)), select(self.next_tab(true, true)))
+        } else {
+            (None, None)
+        };
+        let numbers: Vec<Option<Message>> = (1..=9)
+            .map(|number| if number == 9 { order.last() } else { order.get(number - 1) })
+            .map(|index| index.copied().filter(|index| {
+                self.number_shortcuts && !self.is_tab_disabled(*index)
+            }))
+            .map(select)
+            .collect();
+        let close = self.on_close.as_ref()
+            .filter(|_| self.close_shortcut && self.is_tab_closable(self.active_tab))
+            .filter(|_| self.active_tab < self.tabs.len())
+            .map(|on_close| on_close(self.active_tab));
+
+        move |_root, _vdom, event| {
+            let event = match event.dyn_ref::<web_sys::KeyboardEvent>() {
+                Some(event) => event,
+                None => return,
+            };
+            let key = event.key();
+
+            let message = if event.ctrl_key() {
+                match key.as_str() {
+                    "Tab" if event.shift_key() => cycle_back.clone(),
+                    "Tab" => cycle_forward.clone(),
+                    "w" | "W" => close.clone(),
+                    number => number.parse::<usize>().ok()
+                        .filter(|number| (1..=9).contains(number))
+                        .and_then(|number| numbers[number - 1].clone()),
+                }
+            } else if event.shift_key() || event.alt_key() || event.meta_key() {
+                None
+            } else if key == back_key {
+                back.clone()
+            } else if key == forward_key {
+                forward.clone()
+            } else {
+                None
+            };
+
+            if let Some(message) = message {
+                // Do not scroll the TabBar or run the shortcut of the browser.
+                event.prevent_default();
+                event_bus.publish(message);
+            }
+        }
+    }
+
+    /// Creates the node of the drop down list of all tabs shown by the
+    /// [`overflow_menu`](TabBar::overflow_menu).
+    fn menu_node<'b>(
+        &self,
+        style: &Style,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let event_bus = bus.clone();
+        let messages: Vec<Option<Message>> = (0..self.tabs.len())
+            .map(|index| Some(index)
+                .filter(|index| !self.is_tab_disabled(*index))
+                .map(|index| (self.on_select)(index))
+            )
+            .collect();
+
+        let options: Vec<dodrio::Node<'b>> = self.display_order()
+            .into_iter()
+            .map(|index| {
+                let label = match &self.tabs[index] {
+                    Tab::Label(tab_label) => match (tab_label.get_text(), tab_label.get_icon()) {
+                        (Some(label), _) => bumpalo::format!(in bump, "{}", label),
+                        (None, Some(icon)) => bumpalo::format!(in bump, "{}", icon),
+                        (None, None) => bumpalo::format!(in bump, "{}", index + 1),
+                    },
+                    Tab::Element(_) => bumpalo::format!(in bump, "{}", index + 1),
+                };
+
+                option(bump)
+                    .attr("value", bumpalo::format!(in bump, "{}", index).into_bump_str())
+                    .bool_attr("selected", index == self.active_tab)
+                    .bool_attr("disabled", self.is_tab_disabled(index))
+                    .children(vec![text(label.into_bump_str())])
+                    .finish()
+            })
+            .collect();
+
+        select(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "flex: 0 0 auto; align-self: center; margin: {}px; \
+                    font-size: {}px; color: {}; background: transparent; \
+                    border: none; cursor: pointer;",
+                    self.padding,
+                    self.text_size,
+                    css::color(style.icon_color)
+                )
+                .into_bump_str(),
+            )
+            .children(options)
+            .on("change", move |_root, _vdom, event| {
+                let message = event.target()
+                    .and_then(|target| target.dyn_into::<web_sys::HtmlSelectElement>().ok())
+                    .and_then(|select| select.value().parse::<usize>().ok())
+                    .and_then(|index| messages.get(index).cloned().flatten());
+
+                if let Some(message) = message {
+                    event_bus.publish(message);
+                }
+            })
+            .finish()
+    }
+
+    /// Creates the node of the tab at the given index.
+    fn tab_node<'b>(
+        &self,
+        index: usize,
+        tab: &Tab<'a, Message>,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let is_selected = index == self.active_tab;
+        let is_disabled = self.is_tab_disabled(index);
+        let is_modified = self.is_tab_modified(index);
+        let is_pinned = self.is_tab_pinned(index);
+        let style = if is_disabled {
+            self.style.disabled(is_selected)
+        } else if is_pinned {
+            self.style.pinned(is_selected)
+        } else {
+            self.style.active(is_selected)
+        };
+
+        let text_color = if is_selected {
+            style.active_text_color
+        } else {
+            style.text_color
+        };
+
+        let (label, badge) = match tab {
+            Tab::Label(tab_label) if is_pinned => (
+                self.tab_label_nodes(&tab_label.pinned_label(), &style, text_color, bump),
+                tab_label.get_badge().map(|badge| badge_node(badge, &style, bump)),
+            ),
+            Tab::Label(tab_label) => (
+                self.tab_label_nodes(tab_label, &style, text_color, bump),
+                tab_label.get_badge().map(|badge| badge_node(badge, &style, bump)),
+            ),
+            Tab::Element(element) => (
+                vec![element.node(bump, bus, style_sheet)],
+                None,
+            ),
+        };
+
+        let label = div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: flex; flex: 1; min-width: 0; align-items: center; \
+                    justify-content: center; gap: {}px;",
+                    self.padding
+                )
+                .into_bump_str(),
+            )
+            .children(label)
+            .finish();
+
+        let is_closable = self.is_tab_closable(index);
+
+        let marker = Some(is_modified).filter(|is_modified| *is_modified).map(|_| {
+            span(bump)
+                .attr("class", "iced_aw_tab_bar_marker")
+                .attr(
+                    "style",
+                    bumpalo::format!(
+                        in bump,
+                        "font-size: {}px; color: {};",
+                        self.close_size,
+                        css::color(style.modified_color)
+                    )
+                    .into_bump_str(),
+                )
+                .children(vec![text("●")])
+                .finish()
+        });
+
+        let close = Some(is_closable).filter(|is_closable| *is_closable).map(|_| {
+            span(bump)
+                .attr("class", "iced_aw_tab_bar_close")
+                .attr(
+                    "style",
+                    bumpalo::format!(
+                        in bump,
+                        "font-size: {}px; color: {};",
+                        self.close_size,
+                        css::color(style.icon_color)
+                    )
+                    .into_bump_str(),
+                )
+                .children(vec![text("×")])
+                .finish()
+        });
+
+        // The modified indicator replaces the close icon until the tab is
+        // hovered.
+        let cross = if is_closable || is_modified {
+            let mut cross = span(bump)
+                .attr(
+                    "class",
+                    if is_closable && is_modified { "iced_aw_tab_bar_modified" } else { "" },
+                )
+                .attr(
+                    "style",
+                    if is_closable { "cursor: pointer;" } else { "cursor: default;" },
+                )
+                .children(
+                    vec![marker, close]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<dodrio::Node<'b>>>()
+                );
+
+            if let (true, Some(on_close)) = (is_closable, self.on_close.as_ref()) {
+                let event_bus = bus.clone();
+                let on_close = on_close(index);
+
+                cross = cross.on("click", move |_root, _vdom, event| {
+                    // Do not select the tab that is closed.
+                    event.stop_propagation();
+                    event_bus.publish(on_close.clone());
+                });
+            }
+
+            Some(cross.finish())
+        } else {
+            None
+        };
+
+        let size = match self.orientation {
+            Orientation::Horizontal if is_pinned => String::from("flex: 0 0 auto;"),
+            Orientation::Horizontal => format!(
+                "{} min-width: {}; max-width: {};",
+                match self.tab_width {
+                    Length::Fill => String::from("flex: 1 1 0;"),
+                    Length::FillPortion(portion) => format!("flex: {} 1 0;", portion),
+                    Length::Shrink => String::from("flex: 0 0 auto;"),
+                    Length::Units(units) => format!("flex: 0 0 {}px;", units),
+                },
+                self.min_tab_width.map_or(
+                    String::from("auto"),
+                    |min| format!("{}px", min),
+                ),
+                self.max_tab_width.map_or(
+                    String::from("none"),
+                    |max| format!("{}px", max),
+                ),
+            ),
+            Orientation::Vertical => format!(
+                "flex: 0 0 auto; width: {};",
+                css::length(self.tab_width)
+            ),
+        };
+
+        let indicator = match style.indicator_color {
+            Some(color) if is_selected && style.indicator_thickness > 0 => {
+                let thickness = i32::from(style.indicator_thickness);
+                let (x, y) = match style.indicator_position {
+                    IndicatorPosition::Top => (0, thickness),
+                    IndicatorPosition::Bottom => (0, -thickness),
+                    IndicatorPosition::Left => (thickness, 0),
+                    IndicatorPosition::Right => (-thickness, 0),
+                };
+
+                format!("inset {}px {}px 0 0 {}", x, y, css::color(color))
+            },
+            _ => String::from("none"),
+        };
+
+        let mut node = div(bump)
+            .attr("class", "iced_aw_tab_bar_tab")
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "{} display: flex; align-items: center; position: relative; \
+                    box-sizing: border-box; padding: {}px; background: {}; \
+                    border: {}px solid {}; border-radius: {}px; box-shadow: {}; \
+                    color: {}; cursor: {};",
+                    size,
+                    self.padding,
+                    match style.tab_label_background {
+                        Background::Color(color) => css::color(color),
+                    },
+                    style.tab_label_border_width,
+                    css::color(style.tab_label_border_color),
+                    style.tab_label_border_radius,
+                    indicator,
+                    css::color(text_color),
+                    if is_disabled { "default" } else { "pointer" }
+                )
+                .into_bump_str(),
+            )
+            .children(
+                vec![Some(label), badge, cross]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<dodrio::Node<'b>>>()
+            );
+
+        // The truncation is done by CSS, so the full text is shown for every
+        // label.
+        if let Some(title) = match tab {
+            Tab::Label(tab_label) => tab_label.get_text(),
+            Tab::Element(_) => None,
+        } {
+            node = node.attr(
+                "title",
+                bumpalo::format!(in bump, "{}", title).into_bump_str(),
+            );
+        }
+
+        if !is_disabled {
+            let event_bus = bus.clone();
+            let on_select = (self.on_select)(index);
+
+            node = node.on("click", move |_root, _vdom, _event| {
+                event_bus.publish(on_select.clone());
+            });
+        }
+
+        // Pinned tabs can neither be dragged nor be dropped on.
+        if let (false, Some(on_reorder)) = (is_pinned, self.on_reorder.as_ref()) {
+            if !is_disabled {
+                node = node
+                    .attr("draggable", "true")
+                    .on("dragstart", move |_root, _vdom, event| {
+                        let data_transfer = event.dyn_ref::<web_sys::DragEvent>()
+                            .and_then(web_sys::DragEvent::data_transfer);
+
+                        if let Some(data_transfer) = data_transfer {
+                            let _ = data_transfer.set_data(DRAG_DATA_TYPE, &index.to_string());
+                        }
+                    });
+            }
+
+            // A tab dropped on this tab takes its position.
+            let event_bus = bus.clone();
+            let on_reorder = Rc::clone(on_reorder);
+            let pinned_tabs = self.pinned_tabs.clone();
+
+            node = node
+                .on("dragover", |_root, _vdom, event| {
+                    // Allow the dropping on this tab.
+                    event.prevent_default();
+                })
+                .on("drop", move |_root, _vdom, event| {
+                    event.prevent_default();
+
+                    let message = event.dyn_ref::<web_sys::DragEvent>()
+                        .and_then(web_sys::DragEvent::data_transfer)
+                        .and_then(|data_transfer| data_transfer.get_data(DRAG_DATA_TYPE).ok())
+                        .and_then(|from| from.parse::<usize>().ok())
+                        .filter(|from| *from != index && !pinned_tabs.contains(from))
+                        .map(|from| on_reorder(from, index));
+
+                    if let Some(message) = message {
+                        event_bus.publish(message);
+                    }
+                });
+        }
+
+        node.finish()
+    }
+
+    /// Creates the nodes of the content of a [`TabLabel`](TabLabel).
+    fn tab_label_nodes<'b>(
+        &self,
+        tab_label: &TabLabel,
+        style: &Style,
+        text_color: Color,
+        bump: &'b bumpalo::Bump,
+    ) -> Vec<dodrio::Node<'b>> {
+        use dodrio::builder::*;
+
+        let icon_node = |icon: char| span(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "font-family: {}; font-size: {}px; color: {};",
+                    font_family(self.icon_font),
+                    self.icon_size,
+                    css::color(style.icon_color)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![text(bumpalo::format!(in bump, "{}", icon).into_bump_str())])
+            .finish();
+
+        let text_node = |label: &str| span(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "font-family: {}; font-size: {}px; color: {}; \
+                    white-space: nowrap; overflow: hidden; text-overflow: ellipsis;",
+                    font_family(self.text_font),
+                    self.text_size,
+                    css::color(text_color)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![text(bumpalo::format!(in bump, "{}", label).into_bump_str())])
+            .finish();
+
+        match tab_label {
+            TabLabel::Icon(icon) => vec![icon_node(*icon)],
+            TabLabel::Text(label) => vec![text_node(label)],
+            TabLabel::IconText(icon, label) => vec![icon_node(*icon), text_node(label)],
+            TabLabel::WithBadge(label, _badge) => {
+                self.tab_label_nodes(label, style, text_color, bump)
+            },
+        }
+    }
+}
+
+/// Creates the node of a [`TabBadge`](TabBadge) placed in the upper right
+/// corner of its tab.
+fn badge_node<'b>(
+    badge: TabBadge,
+    style: &Style,
+    bump: &'b bumpalo::Bump,
+) -> dodrio::Node<'b> {
+    use dodrio::builder::*;
+
+    let background = match style.badge_background {
+        Background::Color(color) => css::color(color),
+    };
+
+    match badge {
+        TabBadge::Dot => span(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "position: absolute; top: 2px; right: 2px; width: 8px; \
+                    height: 8px; border-radius: 4px; background: {};",
+                    background
+                )
+                .into_bump_str(),
+            )
+            .finish(),
+        TabBadge::Count(count) => span(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "position: absolute; top: 2px; right: 2px; min-width: {}px; \
+                    padding: 2px; box-sizing: border-box; border-radius: {}px; \
+                    background: {}; color: {}; font-size: {}px; line-height: 1; \
+                    text-align: center;",
+                    BADGE_TEXT_SIZE + 4,
+                    (BADGE_TEXT_SIZE + 4) / 2,
+                    background,
+                    css::color(style.badge_text_color),
+                    BADGE_TEXT_SIZE
+                )
+                .into_bump_str(),
+            )
+            .children(vec![text(bumpalo::format!(in bump, "{}", count).into_bump_str())])
+            .finish(),
+    }
+}
+
+/// Returns the css font family of the given [`Font`](iced_web::Font).
+fn font_family(font: Option<Font>) -> &'static str {
+    match font {
+        Some(Font::External { name, .. }) => name,
+        _ => "inherit",
+    }
+}
+
+/// The state of a [`TabBar`](TabBar).
+/// 
+/// The web version of the [`TabBar`](TabBar) does not need to keep any
+/// state. It only exists to share the same API with the native version.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`](State) of a [`TabBar`](TabBar).
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+impl<'a, Message> From<TabBar<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn from(tab_bar: TabBar<'a, Message>) -> Element<'a, Message> {
+        Element::new(tab_bar)
+    }
+}
\ No newline at end of file
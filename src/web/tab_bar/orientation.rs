@@ -0,0 +1,17 @@
+//! An [`Orientation`](Orientation) for defining the direction in which the
+//! tabs of a [`TabBar`](super::TabBar) are placed.
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+
+/// An [`Orientation`](Orientation) for defining the direction in which the
+/// tabs of a [`TabBar`](super::TabBar) are placed.
+#[derive(Copy, Clone, Debug, Hash)]
+pub enum Orientation {
+    /// Places the tabs of the [`TabBar`](super::TabBar) next to each other
+    /// in a row.
+    Horizontal,
+
+    /// Places the tabs of the [`TabBar`](super::TabBar) below each other
+    /// in a column.
+    Vertical,
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+//! A [`Tab`](Tab) holding the label of a tab on a
+//! [`TabBar`](super::TabBar).
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+use iced_web::Element;
+
+use super::TabLabel;
+
+/// A [`Tab`](Tab) holding either a predefined [`TabLabel`](super::TabLabel)
+/// or an arbitrary [`Element`](iced_web::Element) as the label of a tab
+/// on a [`TabBar`](super::TabBar).
+#[allow(missing_debug_implementations)]
+pub enum Tab<'a, Message> {
+    /// A [`Tab`](Tab) showing a predefined [`TabLabel`](super::TabLabel).
+    Label(TabLabel),
+
+    /// A [`Tab`](Tab) showing an arbitrary
+    /// [`Element`](iced_web::Element) as its label.
+    Element(Element<'a, Message>),
+}
+
+impl<'a, Message> From<TabLabel> for Tab<'a, Message> {
+    fn from(tab_label: TabLabel) -> Self {
+        Tab::Label(tab_label)
+    }
+}
@@ -0,0 +1,75 @@
+//! A [`TabLabel`](TabLabel) showing an icon and/or a text on a tab.
+//! 
+//! *This API requires the following crate features to be activated: tab_bar*
+use super::TabBadge;
+
+/// A [`TabLabel`](TabLabel) showing an icon and/or a text on a tab
+/// on a [`TabBar`](super::TabBar).
+#[allow(missing_debug_implementations)]
+#[derive(Clone, Hash)]
+pub enum TabLabel {
+
+    /// A [`TabLabel`](TabLabel) showing only an icon on the tab.
+    Icon(char),
+
+    /// A [`TabLabel`](TabLabel) showing only a text on the tab.
+    Text(String),
+
+    /// A [`TabLabel`](TabLabel) showing an icon and a text on the tab.
+    IconText(char, String),
+
+    /// A [`TabLabel`](TabLabel) showing the given label along with a
+    /// [`TabBadge`](super::TabBadge) in the corner of the tab.
+    WithBadge(Box<TabLabel>, TabBadge),
+}
+
+impl TabLabel {
+    /// Adds the given [`TabBadge`](super::TabBadge) to the
+    /// [`TabLabel`](TabLabel), replacing its previous badge.
+    pub fn badge(self, badge: TabBadge) -> Self {
+        match self {
+            TabLabel::WithBadge(label, _) => TabLabel::WithBadge(label, badge),
+            label => TabLabel::WithBadge(Box::new(label), badge),
+        }
+    }
+
+    /// Gets the [`TabBadge`](super::TabBadge) of the [`TabLabel`](TabLabel).
+    pub fn get_badge(&self) -> Option<TabBadge> {
+        match self {
+            TabLabel::WithBadge(_, badge) => Some(*badge),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`TabLabel`](TabLabel) shown on a pinned tab.
+    /// 
+    /// A pinned tab only shows the icon of its label. Labels without an icon
+    /// are kept as they are.
+    pub fn pinned_label(&self) -> TabLabel {
+        match self {
+            TabLabel::IconText(icon, _) => TabLabel::Icon(*icon),
+            TabLabel::WithBadge(label, badge) => {
+                TabLabel::WithBadge(Box::new(label.pinned_label()), *badge)
+            },
+            label => label.clone(),
+        }
+    }
+
+    /// Gets the text of the [`TabLabel`](TabLabel).
+    pub fn get_text(&self) -> Option<&str> {
+        match self {
+            TabLabel::Icon(_) => None,
+            TabLabel::Text(text) | TabLabel::IconText(_, text) => Some(text),
+            TabLabel::WithBadge(label, _) => label.get_text(),
+        }
+    }
+
+    /// Gets the icon of the [`TabLabel`](TabLabel).
+    pub fn get_icon(&self) -> Option<char> {
+        match self {
+            TabLabel::Text(_) => None,
+            TabLabel::Icon(icon) | TabLabel::IconText(icon, _) => Some(*icon),
+            TabLabel::WithBadge(label, _) => label.get_icon(),
+        }
+    }
+}
\ No newline at end of file
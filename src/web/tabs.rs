@@ -0,0 +1,487 @@
+//! Displays a [`Tabs`](Tabs) widget to select the content to be displayed.
+//! 
+//! This is a wrapper around the [`TabBar`](super::tab_bar::TabBar) widget.
+//! Unlike the [`TabBar`](super::tab_bar::TabBar) widget it will also handle
+//! the content of the tabs.
+//! 
+//! *This API requires the following crate features to be activated: tabs*
+use iced_web::{css, Bus, Css, Element, Font, Length, Widget};
+use dodrio::bumpalo;
+
+use crate::web::tab_bar::{self, Orientation, TabBar, TabLabel};
+
+pub mod tab_bar_position;
+pub use tab_bar_position::TabBarPosition;
+
+pub use crate::style::tab_bar::{IndicatorPosition, Style, StyleSheet};
+
+/// A [`Tabs`](Tabs) widget for showing a [`TabBar`](super::tab_bar::TabBar)
+/// along with the tab's content.
+/// 
+/// TODO: Example
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message> {
+    tab_bar: TabBar<'a, Message>,
+    content: Content<'a, Message>,
+    tab_bar_position: TabBarPosition,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Message> Tabs<'a, Message> {
+    /// Creates a new [`Tabs`](Tabs) widget with the [`State`](super::tab_bar::State)
+    /// of its [`TabBar`](super::tab_bar::TabBar), the index of the selected tab
+    /// and a specified message which will be send when a tab is selected by
+    /// the user.
+    pub fn new<F>(state: &'a mut tab_bar::State, active_tab: usize, on_select: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        Self::with_tabs(state, active_tab, Vec::new(), on_select)
+    }
+
+    /// Similar to `new` but with a given Vector of the
+    /// [`TabLabel`](super::tab_bar::TabLabel) along with the tab's content.
+    pub fn with_tabs<F>(
+        state: &'a mut tab_bar::State,
+        active_tab: usize,
+        tabs: Vec<(TabLabel, Element<'a, Message>)>,
+        on_select: F,
+    ) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        let mut tab_labels = Vec::with_capacity(tabs.len());
+        let mut elements = Vec::with_capacity(tabs.len());
+
+        for (tab_label, element) in tabs {
+            tab_labels.push(tab_label);
+            elements.push(element);
+        }
+
+        Tabs {
+            tab_bar: TabBar::width_tab_labels(
+                state,
+                active_tab,
+                tab_labels,
+                on_select,
+            ),
+            content: Content::Eager(elements),
+            tab_bar_position: TabBarPosition::Top,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Creates a new lazy [`Tabs`](Tabs) widget with the given
+    /// [`TabLabel`](super::tab_bar::TabLabel)s.
+    /// 
+    /// Unlike [`with_tabs`](Tabs::with_tabs), only the content of the active
+    /// tab is built by calling `content` with its index. As `content` is
+    /// called at most once, the returned [`Element`](iced_web::Element) may
+    /// borrow mutable state of the application.
+    /// 
+    /// Tabs added later with [`push`](Tabs::push) or
+    /// [`push_element`](Tabs::push_element) only keep their content if they
+    /// are the active tab.
+    pub fn lazy<C, F>(
+        state: &'a mut tab_bar::State,
+        active_tab: usize,
+        tab_labels: Vec<TabLabel>,
+        content: C,
+        on_select: F,
+    ) -> Self
+    where
+        C: FnOnce(usize) -> Element<'a, Message>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        let active_content = if active_tab < tab_labels.len() {
+            Some(content(active_tab))
+        } else {
+            None
+        };
+
+        Tabs {
+            tab_bar: TabBar::width_tab_labels(
+                state,
+                active_tab,
+                tab_labels,
+                on_select,
+            ),
+            content: Content::Lazy(active_content),
+            tab_bar_position: TabBarPosition::Top,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Sets whether the tab at the given index is disabled.
+    /// 
+    /// A disabled tab can neither be selected nor closed by the user.
+    pub fn tab_disabled(mut self, index: usize, disabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.tab_disabled(index, disabled);
+        self
+    }
+
+    /// Sets whether the tab at the given index is marked as modified.
+    /// 
+    /// A modified tab shows an indicator in place of its close icon.
+    pub fn tab_modified(mut self, index: usize, modified: bool) -> Self {
+        self.tab_bar = self.tab_bar.tab_modified(index, modified);
+        self
+    }
+
+    /// Sets whether the tab at the given index is pinned.
+    /// 
+    /// A pinned tab only shows the icon of its label and is placed in front
+    /// of the unpinned tabs, where it keeps its position while the tabs are
+    /// scrolled. It can't be closed by the user.
+    pub fn tab_pinned(mut self, index: usize, pinned: bool) -> Self {
+        self.tab_bar = self.tab_bar.tab_pinned(index, pinned);
+        self
+    }
+
+    /// Sets the message that will be produced when the close icon of a tab
+    /// on the [`TabBar`](TabBar) is pressed.
+    /// 
+    /// Setting this enables the drawing of a close icon on the tabs.
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_close(on_close);
+        self
+    }
+
+    /// Sets the message that will be produced when a tab on the
+    /// [`TabBar`](TabBar) is dragged to a new position.
+    /// 
+    /// The message is created from the index the tab was dragged from and
+    /// the index it should be moved to. The [`Tabs`](Tabs) do not reorder
+    /// their content by themselves, so the application has to move its tab
+    /// at `from` to `to`.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_reorder(on_reorder);
+        self
+    }
+
+    /// Sets the message that will be produced when the button for creating
+    /// a new tab on the [`TabBar`](TabBar) is pressed.
+    /// 
+    /// The [`Tabs`](Tabs) do not add a tab by themselves, so the application
+    /// has to push the new tab and its content.
+    pub fn on_new(mut self, on_new: Message) -> Self {
+        self.tab_bar = self.tab_bar.on_new(on_new);
+        self
+    }
+
+    /// Sets whether the [`TabBar`](TabBar) shows a menu that lists all
+    /// tabs, including those scrolled out of view.
+    /// 
+    /// On the web, the menu is a drop down list behind the tabs.
+    pub fn overflow_menu(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.overflow_menu(enabled);
+        self
+    }
+
+    /// Sets the width of the [`Tabs`](Tabs).
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`](Tabs).
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the width of the [`TabBar`](super::tab_bar::TabBar) of the
+    /// [`Tabs`](Tabs).
+    pub fn tab_bar_width(mut self, width: Length) -> Self {
+        self.tab_bar = self.tab_bar.width(width);
+        self
+    }
+
+    /// Sets the height of the [`TabBar`](super::tab_bar::TabBar) of the
+    /// [`Tabs`](Tabs).
+    pub fn tab_bar_height(mut self, height: Length) -> Self {
+        self.tab_bar = self.tab_bar.height(height);
+        self
+    }
+
+    /// Sets the width of a tab on the [`TabBar`](super::tab_bar::TabBar) of
+    /// the [`Tabs`](Tabs).
+    pub fn tab_width(mut self, width: Length) -> Self {
+        self.tab_bar = self.tab_bar.tab_width(width);
+        self
+    }
+
+    /// Sets the minimum width of each tab on a horizontal
+    /// [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs).
+    pub fn min_tab_width(mut self, min_tab_width: u16) -> Self {
+        self.tab_bar = self.tab_bar.min_tab_width(min_tab_width);
+        self
+    }
+
+    /// Sets the maximum width of each tab on a horizontal
+    /// [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs).
+    pub fn max_tab_width(mut self, max_tab_width: u16) -> Self {
+        self.tab_bar = self.tab_bar.max_tab_width(max_tab_width);
+        self
+    }
+
+    /// Sets the maximum height of the [`TabBar`](super::tab_bar::TabBar) of the
+    /// [`Tabs`](Tabs).
+    pub fn tab_bar_max_height(mut self, max_height: u32) -> Self {
+        self.tab_bar = self.tab_bar.max_height(max_height);
+        self
+    }
+
+    /// Sets the icon size of the [`TabLabel`](super::tab_bar::TabLabel) of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn icon_size(mut self, icon_size: u16) -> Self {
+        self.tab_bar = self.tab_bar.icon_size(icon_size);
+        self
+    }
+
+    /// Sets the text size of the [`TabLabel`](super::tab_bar::TabLabel) of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.tab_bar = self.tab_bar.text_size(text_size);
+        self
+    }
+
+    /// Sets the size of the close icon of the
+    /// [`TabLabel`](super::tab_bar::TabLabel) of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn close_size(mut self, close_size: u16) -> Self {
+        self.tab_bar = self.tab_bar.close_size(close_size);
+        self
+    }
+
+    /// Sets the padding of the tabs of the [`TabBar`](super::tab_bar::TabBar).
+    pub fn tab_label_padding(mut self, padding: u16) -> Self {
+        self.tab_bar = self.tab_bar.padding(padding);
+        self
+    }
+
+    /// Sets the spacing between the tabs of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn tab_label_spacing(mut self, spacing: u16) -> Self {
+        self.tab_bar = self.tab_bar.spacing(spacing);
+        self
+    }
+
+    /// Sets the font of the icons of the
+    /// [`TabLabel`](super::tab_bar::TabLabel)s of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn icon_font(mut self, icon_font: Font) -> Self {
+        self.tab_bar = self.tab_bar.icon_font(icon_font);
+        self
+    }
+
+    /// Sets the font of the text of the
+    /// [`TabLabel`](super::tab_bar::TabLabel)s of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    pub fn text_font(mut self, text_font: Font) -> Self {
+        self.tab_bar = self.tab_bar.text_font(text_font);
+        self
+    }
+
+    /// Sets whether the tabs can be cycled with `Ctrl+Tab` and
+    /// `Ctrl+Shift+Tab` while the [`TabBar`](TabBar) is focused.
+    /// 
+    /// Most browsers reserve this shortcut for their own tabs, so it only
+    /// reaches the [`Tabs`](Tabs) of an installed web app.
+    /// 
+    /// This is enabled by default.
+    pub fn cycle_shortcuts(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.cycle_shortcuts(enabled);
+        self
+    }
+
+    /// Sets whether the first eight tabs can be selected with `Ctrl+1` to
+    /// `Ctrl+8` and the last tab with `Ctrl+9` while the [`TabBar`](TabBar)
+    /// is focused.
+    /// 
+    /// Most browsers reserve these shortcuts for their own tabs, so they only
+    /// reach the [`Tabs`](Tabs) of an installed web app.
+    /// 
+    /// This is enabled by default.
+    pub fn number_shortcuts(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.number_shortcuts(enabled);
+        self
+    }
+
+    /// Sets whether the active tab can be closed with `Ctrl+W` while the
+    /// [`TabBar`](TabBar) is focused. This only has an effect if
+    /// [`on_close`](Tabs::on_close) is set.
+    /// 
+    /// Most browsers reserve this shortcut for closing their own tabs, so it
+    /// only reaches the [`Tabs`](Tabs) of an installed web app.
+    /// 
+    /// This is enabled by default.
+    pub fn close_shortcut(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.close_shortcut(enabled);
+        self
+    }
+
+    /// Sets whether the arrow keys select the previous or next tab while the
+    /// [`TabBar`](super::tab_bar::TabBar) is focused.
+    /// 
+    /// This is enabled by default.
+    pub fn arrow_navigation(mut self, enabled: bool) -> Self {
+        self.tab_bar = self.tab_bar.arrow_navigation(enabled);
+        self
+    }
+
+    /// Sets the style of the [`TabBar`](super::tab_bar::TabBar).
+    pub fn tab_bar_style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.tab_bar = self.tab_bar.style(style);
+        self
+    }
+
+    /// Sets the [`TabBarPosition`](TabBarPosition) of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    /// 
+    /// Placing the [`TabBar`](super::tab_bar::TabBar) on the left or right
+    /// side of the content will change its
+    /// [`Orientation`](super::tab_bar::Orientation) to vertical. You may want
+    /// to restrict its width by using [`tab_bar_width`](Tabs::tab_bar_width)
+    /// and [`tab_width`](Tabs::tab_width).
+    pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
+        self.tab_bar = self.tab_bar.orientation(match position {
+            TabBarPosition::Top | TabBarPosition::Bottom => Orientation::Horizontal,
+            TabBarPosition::Left | TabBarPosition::Right => Orientation::Vertical,
+        });
+        self.tab_bar_position = position;
+        self
+    }
+
+    /// Returns the content of the active tab of the [`Tabs`](Tabs).
+    fn active_content(&self) -> Option<&Element<'a, Message>> {
+        match &self.content {
+            Content::Eager(elements) => elements.get(self.tab_bar.get_active_tab()),
+            Content::Lazy(content) => content.as_ref(),
+        }
+    }
+
+    /// Adds the content of a tab pushed at the given index.
+    /// 
+    /// Lazy [`Tabs`](Tabs) only keep the content if it belongs to the
+    /// active tab and no content was built yet.
+    fn push_content(&mut self, index: usize, element: Element<'a, Message>) {
+        match &mut self.content {
+            Content::Eager(elements) => elements.push(element),
+            Content::Lazy(content) => {
+                if content.is_none() && index == self.tab_bar.get_active_tab() {
+                    *content = Some(element);
+                }
+            },
+        }
+    }
+
+    /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with the tabs
+    /// content to the [`Tabs`](Tabs).
+    /// 
+    /// If the [`Tabs`](Tabs) were created with [`lazy`](Tabs::lazy), the
+    /// content is dropped unless the pushed tab is the active tab.
+    pub fn push<E>(mut self, tab_label: TabLabel, element: E) -> Self
+    where
+        E: Into<Element<'a, Message>>,
+    {
+        let index = self.tab_bar.tab_count();
+        self.tab_bar = self.tab_bar.push(tab_label);
+        self.push_content(index, element.into());
+        self
+    }
+
+    /// Pushes an arbitrary [`Element`](iced_web::Element) as the label of
+    /// a tab along with the tabs content to the [`Tabs`](Tabs).
+    /// 
+    /// If the [`Tabs`](Tabs) were created with [`lazy`](Tabs::lazy), the
+    /// content is dropped unless the pushed tab is the active tab.
+    pub fn push_element<L, E>(mut self, label: L, element: E) -> Self
+    where
+        L: Into<Element<'a, Message>>,
+        E: Into<Element<'a, Message>>,
+    {
+        let index = self.tab_bar.tab_count();
+        self.tab_bar = self.tab_bar.push_element(label);
+        self.push_content(index, element.into());
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Tabs<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let tab_bar = self.tab_bar.node(bump, bus, style_sheet);
+
+        let content = div(bump)
+            .attr(
+                "style",
+                "flex: 1; overflow: auto;",
+            )
+            .children(
+                self.active_content()
+                    .map(|element| element.node(bump, bus, style_sheet))
+                    .into_iter()
+                    .collect::<Vec<dodrio::Node<'b>>>()
+            )
+            .finish();
+
+        let (flex_direction, children) = match self.tab_bar_position {
+            TabBarPosition::Top => ("column", vec![tab_bar, content]),
+            TabBarPosition::Bottom => ("column", vec![content, tab_bar]),
+            TabBarPosition::Left => ("row", vec![tab_bar, content]),
+            TabBarPosition::Right => ("row", vec![content, tab_bar]),
+        };
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: flex; flex-direction: {}; width: {}; height: {};",
+                    flex_direction,
+                    css::length(self.width),
+                    css::length(self.height)
+                )
+                .into_bump_str(),
+            )
+            .children(children)
+            .finish()
+    }
+}
+
+/// The content of the tabs of a [`Tabs`](Tabs) widget.
+enum Content<'a, Message> {
+    /// The content of all tabs.
+    Eager(Vec<Element<'a, Message>>),
+
+    /// The content of only the active tab.
+    Lazy(Option<Element<'a, Message>>),
+}
+
+impl<'a, Message> From<Tabs<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn from(tabs: Tabs<'a, Message>) -> Element<'a, Message> {
+        Element::new(tabs)
+    }
+}
\ No newline at end of file